@@ -0,0 +1,206 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The date of Easter and the moveable feasts depending on it.
+//!
+//! The Western churches use the Gregorian computus,
+//! while the Orthodox churches use the Julian computus on the Julian calendar.
+//! Both are returned as `NaiveDate` in the proleptic Gregorian calendar.
+//!
+//! ~~~~
+//! use chrono::NaiveDate;
+//! use chrono::calendar::computus::{easter_sunday, julian_easter_sunday, Feast};
+//!
+//! assert_eq!(easter_sunday(2019), NaiveDate::from_ymd(2019, 4, 21));
+//! assert_eq!(julian_easter_sunday(2019), NaiveDate::from_ymd(2019, 4, 28));
+//! assert_eq!(Feast::Pentecost.date(2019), NaiveDate::from_ymd(2019, 6, 9));
+//! ~~~~
+
+use oldtime::Duration as OldDuration;
+
+use div::{div_floor, mod_floor};
+use naive::NaiveDate;
+use super::{Calendar, Julian};
+
+/// Returns the date of Easter Sunday in given year, following the Gregorian computus.
+///
+/// The computus is applied proleptically to the years before 1583.
+///
+/// Panics on the out-of-range year.
+pub fn easter_sunday(year: i32) -> NaiveDate {
+    easter_sunday_opt(year).expect("out-of-range year")
+}
+
+/// Returns the date of Easter Sunday in given year, following the Gregorian computus.
+///
+/// Returns `None` on the out-of-range year.
+pub fn easter_sunday_opt(year: i32) -> Option<NaiveDate> {
+    // the anonymous Gregorian algorithm (Meeus/Jones/Butcher)
+    let y = year as i64;
+    let a = mod_floor(y, 19);
+    let (b, c) = (div_floor(y, 100), mod_floor(y, 100));
+    let (d, e) = (div_floor(b, 4), mod_floor(b, 4));
+    let f = div_floor(b + 8, 25);
+    let g = div_floor(b - f + 1, 3);
+    let h = mod_floor(19 * a + b - d - g + 15, 30);
+    let (i, k) = (c / 4, c % 4);
+    let l = mod_floor(32 + 2 * e + 2 * i - h - k, 7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let n = h + l - 7 * m + 114;
+    NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+/// Returns the date of Easter Sunday in given year,
+/// following the Julian computus used by the Orthodox churches.
+///
+/// The resulting date is in the proleptic Gregorian calendar like every `NaiveDate`;
+/// use [`Julian::to_ymd`](../trait.Calendar.html#method.to_ymd) for the Julian calendar date.
+///
+/// Panics on the out-of-range year.
+pub fn julian_easter_sunday(year: i32) -> NaiveDate {
+    julian_easter_sunday_opt(year).expect("out-of-range year")
+}
+
+/// Returns the date of Easter Sunday in given year,
+/// following the Julian computus used by the Orthodox churches.
+///
+/// Returns `None` on the out-of-range year.
+pub fn julian_easter_sunday_opt(year: i32) -> Option<NaiveDate> {
+    // Meeus' Julian algorithm
+    let y = year as i64;
+    let (a, b, c) = (mod_floor(y, 4), mod_floor(y, 7), mod_floor(y, 19));
+    let d = (19 * c + 15) % 30;
+    let e = mod_floor(2 * a + 4 * b - d + 34, 7);
+    let n = d + e + 114;
+    Julian.ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
+}
+
+/// The moveable feasts, whose dates are determined by the date of Easter Sunday.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+pub enum Feast {
+    /// Ash Wednesday, 46 days before Easter Sunday.
+    AshWednesday,
+    /// Palm Sunday, a week before Easter Sunday.
+    PalmSunday,
+    /// Maundy Thursday, 3 days before Easter Sunday.
+    MaundyThursday,
+    /// Good Friday, 2 days before Easter Sunday.
+    GoodFriday,
+    /// Holy Saturday, a day before Easter Sunday.
+    HolySaturday,
+    /// Easter Sunday itself.
+    EasterSunday,
+    /// Easter Monday, a day after Easter Sunday.
+    EasterMonday,
+    /// Ascension Day, 39 days after Easter Sunday.
+    AscensionDay,
+    /// Pentecost (Whit Sunday), 49 days after Easter Sunday.
+    Pentecost,
+    /// Whit Monday, 50 days after Easter Sunday.
+    WhitMonday,
+    /// Trinity Sunday, 56 days after Easter Sunday.
+    TrinitySunday,
+    /// Corpus Christi, 60 days after Easter Sunday.
+    CorpusChristi,
+}
+
+impl Feast {
+    /// Returns the number of days from Easter Sunday to this feast.
+    ///
+    /// This can be combined with [`julian_easter_sunday`](./fn.julian_easter_sunday.html)
+    /// for the feasts of the Orthodox churches.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Duration};
+    /// use chrono::calendar::computus::{julian_easter_sunday, Feast};
+    ///
+    /// let days = Feast::Pentecost.days_from_easter();
+    /// let pentecost = julian_easter_sunday(2019) + Duration::days(days);
+    /// assert_eq!(pentecost, NaiveDate::from_ymd(2019, 6, 16));
+    /// ~~~~
+    pub fn days_from_easter(&self) -> i64 {
+        match *self {
+            Feast::AshWednesday => -46,
+            Feast::PalmSunday => -7,
+            Feast::MaundyThursday => -3,
+            Feast::GoodFriday => -2,
+            Feast::HolySaturday => -1,
+            Feast::EasterSunday => 0,
+            Feast::EasterMonday => 1,
+            Feast::AscensionDay => 39,
+            Feast::Pentecost => 49,
+            Feast::WhitMonday => 50,
+            Feast::TrinitySunday => 56,
+            Feast::CorpusChristi => 60,
+        }
+    }
+
+    /// Returns the date of this feast in given year, following the Gregorian computus.
+    ///
+    /// Panics on the out-of-range year.
+    pub fn date(&self, year: i32) -> NaiveDate {
+        self.date_opt(year).expect("out-of-range year")
+    }
+
+    /// Returns the date of this feast in given year, following the Gregorian computus.
+    ///
+    /// Returns `None` on the out-of-range year.
+    pub fn date_opt(&self, year: i32) -> Option<NaiveDate> {
+        let easter = try_opt!(easter_sunday_opt(year));
+        easter.checked_add_signed(OldDuration::days(self.days_from_easter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+    use {Datelike, Weekday};
+    use super::{easter_sunday, easter_sunday_opt, julian_easter_sunday, julian_easter_sunday_opt};
+    use super::Feast;
+
+    #[test]
+    fn test_easter_sunday() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        for &(year, month, day) in &[(1818, 3, 22), (1943, 4, 25), (2000, 4, 23), (2019, 4, 21),
+                                     (2023, 4, 9), (2024, 3, 31), (2025, 4, 20), (2038, 4, 25),
+                                     (2285, 3, 22)] {
+            assert_eq!(easter_sunday(year), ymd(year, month, day));
+        }
+        for &(year, month, day) in &[(2000, 4, 30), (2010, 4, 4), (2017, 4, 16), (2019, 4, 28),
+                                     (2023, 4, 16), (2024, 5, 5), (2025, 4, 20)] {
+            assert_eq!(julian_easter_sunday(year), ymd(year, month, day));
+        }
+    }
+
+    #[test]
+    fn test_easter_sunday_is_sunday() {
+        for year in (-2000..3000).chain(MAX_DATE.year() - 10..MAX_DATE.year() + 1) {
+            let western = easter_sunday(year);
+            assert_eq!(western.weekday(), Weekday::Sun);
+            assert!(NaiveDate::from_ymd(year, 3, 22) <= western);
+            assert!(western <= NaiveDate::from_ymd(year, 4, 25));
+        }
+        for year in -2000..3000 {
+            assert_eq!(julian_easter_sunday(year).weekday(), Weekday::Sun);
+        }
+        assert_eq!(easter_sunday_opt(MIN_DATE.year() - 1), None);
+        assert_eq!(easter_sunday_opt(MAX_DATE.year() + 1), None);
+        // the Julian calendar lags behind, so this would be thousands of days after `MAX_DATE`
+        assert_eq!(julian_easter_sunday_opt(MAX_DATE.year()), None);
+    }
+
+    #[test]
+    fn test_feasts() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(Feast::AshWednesday.date(2019), ymd(2019, 3, 6));
+        assert_eq!(Feast::GoodFriday.date(2019), ymd(2019, 4, 19));
+        assert_eq!(Feast::EasterSunday.date(2019), ymd(2019, 4, 21));
+        assert_eq!(Feast::EasterMonday.date(2019), ymd(2019, 4, 22));
+        assert_eq!(Feast::AscensionDay.date(2019), ymd(2019, 5, 30));
+        assert_eq!(Feast::Pentecost.date(2019), ymd(2019, 6, 9));
+        assert_eq!(Feast::CorpusChristi.date(2019), ymd(2019, 6, 20));
+        assert_eq!(Feast::AshWednesday.date_opt(MAX_DATE.year() + 1), None);
+    }
+}
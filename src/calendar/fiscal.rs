@@ -0,0 +1,203 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Fiscal years starting on a day other than January 1.
+//!
+//! ~~~~
+//! use chrono::NaiveDate;
+//! use chrono::calendar::fiscal::FiscalCalendar;
+//!
+//! // the U.S. federal government: FY2020 is from October 1, 2019 to September 30, 2020
+//! let us = FiscalCalendar::new(10, 1);
+//! assert_eq!(us.fiscal_year(&NaiveDate::from_ymd(2019, 11, 5)), 2020);
+//! assert_eq!(us.fiscal_quarter(&NaiveDate::from_ymd(2019, 11, 5)), 1);
+//! assert_eq!(us.year_start(2020), Some(NaiveDate::from_ymd(2019, 10, 1)));
+//!
+//! // Japan: FY2019 is from April 1, 2019 to March 31, 2020
+//! let japan = FiscalCalendar::new(4, 1).named_by_start_year();
+//! assert_eq!(japan.fiscal_year(&NaiveDate::from_ymd(2020, 3, 31)), 2019);
+//! assert_eq!(japan.quarter_end(2019, 4), Some(NaiveDate::from_ymd(2020, 3, 31)));
+//! ~~~~
+
+use std::cmp;
+
+use naive::{NaiveDate, Months};
+use Datelike;
+
+/// A fiscal year starting on the given month and day of every year.
+///
+/// The fiscal quarters and months are counted from the start of the fiscal year,
+/// like adding [`Months`](../../struct.Months.html) to it.
+/// By default a fiscal year is named after the calendar year in which it ends,
+/// which can be changed with [`named_by_start_year`](#method.named_by_start_year).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    start_day: u32,
+    by_start_year: bool,
+}
+
+impl FiscalCalendar {
+    /// Makes a new fiscal calendar whose years start on given month and day.
+    ///
+    /// Panics if the month and day are invalid or February 29.
+    pub fn new(start_month: u32, start_day: u32) -> FiscalCalendar {
+        FiscalCalendar::new_opt(start_month, start_day).expect("invalid fiscal year start")
+    }
+
+    /// Makes a new fiscal calendar whose years start on given month and day.
+    ///
+    /// Returns `None` if the month and day are invalid or February 29,
+    /// which does not exist every year.
+    pub fn new_opt(start_month: u32, start_day: u32) -> Option<FiscalCalendar> {
+        // any common year will do
+        NaiveDate::from_ymd_opt(2001, start_month, start_day).map(|_| {
+            FiscalCalendar { start_month: start_month, start_day: start_day, by_start_year: false }
+        })
+    }
+
+    /// Names the fiscal years after the calendar year in which they start.
+    pub fn named_by_start_year(self) -> FiscalCalendar {
+        FiscalCalendar { by_start_year: true, ..self }
+    }
+
+    /// Returns the month in which the fiscal years start, starting from 1.
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+
+    /// Returns the day of month on which the fiscal years start, starting from 1.
+    pub fn start_day(&self) -> u32 {
+        self.start_day
+    }
+
+    /// Returns the calendar year in which given fiscal year starts.
+    fn start_year_of(&self, fiscal_year: i32) -> Option<i32> {
+        if self.by_start_year || (self.start_month, self.start_day) == (1, 1) {
+            Some(fiscal_year)
+        } else {
+            fiscal_year.checked_sub(1)
+        }
+    }
+
+    /// Returns the fiscal year of given date.
+    pub fn fiscal_year(&self, date: &NaiveDate) -> i32 {
+        let started = (date.month(), date.day()) >= (self.start_month, self.start_day);
+        let start_year = if started { date.year() } else { date.year() - 1 };
+        if self.by_start_year || (self.start_month, self.start_day) == (1, 1) {
+            start_year
+        } else {
+            start_year + 1
+        }
+    }
+
+    /// Returns the fiscal quarter of given date, from 1 to 4.
+    pub fn fiscal_quarter(&self, date: &NaiveDate) -> u32 {
+        (self.fiscal_month(date) - 1) / 3 + 1
+    }
+
+    /// Returns the fiscal month of given date, from 1 to 12.
+    pub fn fiscal_month(&self, date: &NaiveDate) -> u32 {
+        // a fiscal month starts on `start_day`, clamped to the end of the shorter months
+        let months = (date.month() + 12 - self.start_month) % 12;
+        if date.day() < cmp::min(self.start_day, date.days_in_month()) {
+            (months + 11) % 12 + 1
+        } else {
+            months + 1
+        }
+    }
+
+    /// Returns the first day of given fiscal year.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn year_start(&self, fiscal_year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(try_opt!(self.start_year_of(fiscal_year)),
+                                self.start_month, self.start_day)
+    }
+
+    /// Returns the last day of given fiscal year.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn year_end(&self, fiscal_year: i32) -> Option<NaiveDate> {
+        self.year_start(try_opt!(fiscal_year.checked_add(1))).and_then(|d| d.pred_opt())
+    }
+
+    /// Returns the first day of given fiscal quarter (from 1 to 4) of given fiscal year.
+    ///
+    /// Returns `None` on the invalid quarter or the out-of-range date.
+    pub fn quarter_start(&self, fiscal_year: i32, quarter: u32) -> Option<NaiveDate> {
+        if quarter < 1 || quarter > 4 {
+            return None;
+        }
+        try_opt!(self.year_start(fiscal_year)).checked_add_months(Months((quarter - 1) * 3))
+    }
+
+    /// Returns the last day of given fiscal quarter (from 1 to 4) of given fiscal year.
+    ///
+    /// Returns `None` on the invalid quarter or the out-of-range date.
+    pub fn quarter_end(&self, fiscal_year: i32, quarter: u32) -> Option<NaiveDate> {
+        if quarter < 1 || quarter > 4 {
+            return None;
+        }
+        if quarter == 4 {
+            return self.year_end(fiscal_year);
+        }
+        try_opt!(self.year_start(fiscal_year)).checked_add_months(Months(quarter * 3))
+                                              .and_then(|d| d.pred_opt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+    use Datelike;
+    use super::FiscalCalendar;
+
+    #[test]
+    fn test_fiscal_calendar() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        let us = FiscalCalendar::new(10, 1);
+        assert_eq!(us.fiscal_year(&ymd(2019, 9, 30)), 2019);
+        assert_eq!(us.fiscal_year(&ymd(2019, 10, 1)), 2020);
+        assert_eq!(us.fiscal_quarter(&ymd(2019, 10, 1)), 1);
+        assert_eq!(us.fiscal_quarter(&ymd(2020, 1, 1)), 2);
+        assert_eq!(us.fiscal_quarter(&ymd(2020, 9, 30)), 4);
+        assert_eq!(us.fiscal_month(&ymd(2019, 10, 31)), 1);
+        assert_eq!(us.fiscal_month(&ymd(2020, 9, 1)), 12);
+        assert_eq!(us.year_start(2020), Some(ymd(2019, 10, 1)));
+        assert_eq!(us.year_end(2020), Some(ymd(2020, 9, 30)));
+        assert_eq!(us.quarter_start(2020, 2), Some(ymd(2020, 1, 1)));
+        assert_eq!(us.quarter_end(2020, 2), Some(ymd(2020, 3, 31)));
+        assert_eq!(us.quarter_start(2020, 0), None);
+        assert_eq!(us.quarter_end(2020, 5), None);
+
+        let uk = FiscalCalendar::new(4, 6).named_by_start_year();
+        assert_eq!((uk.start_month(), uk.start_day()), (4, 6));
+        assert_eq!(uk.fiscal_year(&ymd(2019, 4, 5)), 2018);
+        assert_eq!(uk.fiscal_year(&ymd(2019, 4, 6)), 2019);
+        assert_eq!(uk.fiscal_month(&ymd(2019, 5, 5)), 1);
+        assert_eq!(uk.fiscal_month(&ymd(2019, 5, 6)), 2);
+        assert_eq!(uk.fiscal_quarter(&ymd(2020, 4, 5)), 4);
+        assert_eq!(uk.year_end(2019), Some(ymd(2020, 4, 5)));
+        assert_eq!(uk.quarter_end(2019, 3), Some(ymd(2020, 1, 5)));
+
+        // the end of a month is clamped in the shorter months
+        let late = FiscalCalendar::new(8, 31);
+        assert_eq!(late.quarter_start(2020, 3), Some(ymd(2020, 2, 29)));
+        assert_eq!(late.fiscal_quarter(&ymd(2020, 2, 28)), 2);
+        assert_eq!(late.fiscal_quarter(&ymd(2020, 2, 29)), 3);
+        assert_eq!(late.fiscal_month(&ymd(2019, 9, 30)), 2);
+
+        let calendar_year = FiscalCalendar::new(1, 1);
+        assert_eq!(calendar_year.fiscal_year(&ymd(2020, 12, 31)), 2020);
+        assert_eq!(calendar_year.year_end(2020), Some(ymd(2020, 12, 31)));
+        assert_eq!(calendar_year.year_end(MAX_DATE.year()), None);
+        assert_eq!(us.fiscal_month(&MIN_DATE), 4);
+        assert_eq!(us.fiscal_year(&MIN_DATE), MIN_DATE.year());
+
+        assert_eq!(FiscalCalendar::new_opt(2, 29), None);
+        assert_eq!(FiscalCalendar::new_opt(13, 1), None);
+        assert_eq!(FiscalCalendar::new_opt(4, 31), None);
+    }
+}
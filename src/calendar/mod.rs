@@ -0,0 +1,355 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Non-Gregorian calendars.
+//!
+//! Chrono's date types always use the proleptic Gregorian calendar.
+//! This module converts them from and to the dates in other calendars,
+//! which are represented as a plain `(year, month, day)` tuple.
+//! Every calendar implements the [`Calendar`](./trait.Calendar.html) trait,
+//! which also provides the calendar-aware arithmetic like adding months.
+//! The [`computus`](./computus/index.html) module calculates the date of Easter,
+//! the [`fiscal`](./fiscal/index.html) module handles fiscal years,
+//! and the [`retail`](./retail/index.html) module handles the 4-4-5 retail calendars.
+//!
+//! ~~~~
+//! use chrono::NaiveDate;
+//! use chrono::calendar::{Calendar, Julian};
+//!
+//! // the Gregorian calendar was introduced right after October 4, 1582 (Julian)
+//! assert_eq!(Julian.ymd(1582, 10, 5), NaiveDate::from_ymd(1582, 10, 15));
+//! assert_eq!(Julian.to_ymd(&NaiveDate::from_ymd(1582, 10, 14)), (1582, 10, 4));
+//! ~~~~
+
+use std::cmp;
+
+pub mod computus;
+pub mod fiscal;
+pub mod retail;
+
+use div::{div_floor, div_mod_floor};
+use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+use Datelike;
+
+/// A calendar system, which maps the `(year, month, day)` tuples to the days.
+///
+/// The days are counted as the "fixed" day numbers,
+/// where the day 1 is January 1, 1 in the proleptic Gregorian calendar.
+/// This is same to [`Datelike::num_days_from_ce`](../trait.Datelike.html#method.num_days_from_ce),
+/// so every calendar can be converted from and to `NaiveDate`.
+///
+/// The implementors only have to describe the structure of years and months
+/// and the conversion from and to the fixed day numbers;
+/// the remaining methods are built upon them.
+pub trait Calendar {
+    /// Returns the number of months in given year.
+    fn months_in_year(&self, year: i32) -> u32;
+
+    /// Returns the number of days in given month, or `None` if the month is invalid.
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32>;
+
+    /// Returns the fixed day number of given date in this calendar.
+    ///
+    /// Returns `None` on the invalid month and day or the out-of-range day number.
+    fn to_fixed(&self, year: i32, month: u32, day: u32) -> Option<i32>;
+
+    /// Returns the date in this calendar of given fixed day number.
+    fn from_fixed(&self, fixed: i32) -> (i32, u32, u32);
+
+    /// Makes a new `NaiveDate` from the year, month and day in this calendar.
+    ///
+    /// Panics on the out-of-range date and/or invalid month and day.
+    fn ymd(&self, year: i32, month: u32, day: u32) -> NaiveDate {
+        self.ymd_opt(year, month, day).expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `NaiveDate` from the year, month and day in this calendar.
+    ///
+    /// Returns `None` on the out-of-range date and/or invalid month and day.
+    fn ymd_opt(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(self.to_fixed(year, month, day)))
+    }
+
+    /// Returns the year, month and day in this calendar of given date.
+    fn to_ymd(&self, date: &NaiveDate) -> (i32, u32, u32) {
+        self.from_fixed(date.num_days_from_ce())
+    }
+
+    /// Adds given number of months (which can be negative) to given date in this calendar,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::calendar::{Calendar, Gregorian, Julian};
+    ///
+    /// let d = NaiveDate::from_ymd(2100, 1, 31);
+    /// assert_eq!(Gregorian.checked_add_months(&d, 1), Some(NaiveDate::from_ymd(2100, 2, 28)));
+    ///
+    /// let d = Julian.ymd(2100, 1, 31);
+    /// assert_eq!(Julian.checked_add_months(&d, 1), Some(Julian.ymd(2100, 2, 29)));
+    /// ~~~~
+    fn checked_add_months(&self, date: &NaiveDate, months: i32) -> Option<NaiveDate> {
+        let (min_year, _, _) = self.to_ymd(&MIN_DATE);
+        let (max_year, _, _) = self.to_ymd(&MAX_DATE);
+        let (mut year, month, day) = self.to_ymd(date);
+
+        // the number of months can differ by years, so go over the years one by one
+        let mut month = month as i64 + months as i64;
+        while month > self.months_in_year(year) as i64 {
+            month -= self.months_in_year(year) as i64;
+            year += 1;
+            if year > max_year { return None; }
+        }
+        while month < 1 {
+            year -= 1;
+            if year < min_year { return None; }
+            month += self.months_in_year(year) as i64;
+        }
+
+        let month = month as u32;
+        let day = cmp::min(day, try_opt!(self.days_in_month(year, month)));
+        self.ymd_opt(year, month, day)
+    }
+}
+
+/// The proleptic Gregorian calendar.
+///
+/// This is the calendar used by Chrono's date types,
+/// and mainly useful as a `Calendar` for the generic code.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Gregorian;
+
+impl Gregorian {
+    /// Returns `true` if given year is a leap year in the Gregorian calendar.
+    #[inline]
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+}
+
+impl Calendar for Gregorian {
+    fn months_in_year(&self, _year: i32) -> u32 { 12 }
+
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        days_in_month(month, self.is_leap_year(year))
+    }
+
+    fn to_fixed(&self, year: i32, month: u32, day: u32) -> Option<i32> {
+        let leap = self.is_leap_year(year);
+        if day == 0 || day > try_opt!(days_in_month(month, leap)) {
+            return None;
+        }
+        let y = year as i64 - 1;
+        let fixed = 365 * y + div_floor(y, 4) - div_floor(y, 100) + div_floor(y, 400) +
+                    days_before_month(month, leap) + day as i64;
+        to_i32(fixed)
+    }
+
+    fn from_fixed(&self, fixed: i32) -> (i32, u32, u32) {
+        let (n400, d1) = div_mod_floor(fixed as i64 - 1, 146_097);
+        let (n100, d2) = div_mod_floor(d1, 36_524);
+        let (n4, d3) = div_mod_floor(d2, 1_461);
+        let n1 = d3 / 365;
+        let year = 400 * n400 + 100 * n100 + 4 * n4 + n1;
+        let year = if n100 == 4 || n1 == 4 { year } else { year + 1 } as i32;
+
+        let leap = self.is_leap_year(year);
+        let y = year as i64 - 1;
+        let year_start = 365 * y + div_floor(y, 4) - div_floor(y, 100) + div_floor(y, 400);
+        let (month, day) = month_and_day(fixed as i64 - year_start, leap);
+        (year, month, day)
+    }
+}
+
+/// The proleptic Julian calendar.
+///
+/// Every fourth year is a leap year, and the years are numbered in the same way as
+/// in [`NaiveDate`](../naive/struct.NaiveDate.html) (i.e. 1 BCE is the year 0).
+/// The Julian calendar is still used for the feasts of the Orthodox churches,
+/// so the Christmas on December 25 falls on January 7 in the Gregorian calendar.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::calendar::{Calendar, Julian};
+///
+/// assert_eq!(Julian.ymd(2018, 12, 25), NaiveDate::from_ymd(2019, 1, 7));
+/// assert_eq!(Julian.ymd_opt(2019, 2, 29), None);
+/// assert_eq!(Julian.ymd_opt(2100, 2, 29), Some(NaiveDate::from_ymd(2100, 3, 14)));
+/// ~~~~
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Julian;
+
+/// The fixed day number of the Julian December 31, 1 BCE.
+const JULIAN_DAY_0: i64 = -2;
+
+impl Julian {
+    /// Returns `true` if given year is a leap year in the Julian calendar.
+    #[inline]
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        year % 4 == 0
+    }
+}
+
+impl Calendar for Julian {
+    fn months_in_year(&self, _year: i32) -> u32 { 12 }
+
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        days_in_month(month, self.is_leap_year(year))
+    }
+
+    fn to_fixed(&self, year: i32, month: u32, day: u32) -> Option<i32> {
+        let leap = self.is_leap_year(year);
+        if day == 0 || day > try_opt!(days_in_month(month, leap)) {
+            return None;
+        }
+        let y = year as i64 - 1;
+        let fixed = JULIAN_DAY_0 + 365 * y + div_floor(y, 4) +
+                    days_before_month(month, leap) + day as i64;
+        to_i32(fixed)
+    }
+
+    fn from_fixed(&self, fixed: i32) -> (i32, u32, u32) {
+        let year = div_floor(4 * (fixed as i64 - JULIAN_DAY_0 - 1) + 1_464, 1_461) as i32;
+
+        let leap = self.is_leap_year(year);
+        let y = year as i64 - 1;
+        let year_start = JULIAN_DAY_0 + 365 * y + div_floor(y, 4);
+        let (month, day) = month_and_day(fixed as i64 - year_start, leap);
+        (year, month, day)
+    }
+}
+
+/// Returns the number of days in given month of the Gregorian or Julian calendar.
+fn days_in_month(month: u32, leap: bool) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 => Some(if leap { 29 } else { 28 }),
+        _ => None,
+    }
+}
+
+/// Returns the number of days in the year before given month
+/// of the Gregorian or Julian calendar.
+fn days_before_month(month: u32, leap: bool) -> i64 {
+    let correction = if month <= 2 { 0 } else if leap { -1 } else { -2 };
+    (367 * month as i64 - 362) / 12 + correction
+}
+
+/// Returns the month and day of the Gregorian or Julian calendar
+/// from the day of year starting from 1.
+fn month_and_day(ordinal: i64, leap: bool) -> (u32, u32) {
+    let correction = if ordinal <= days_before_month(3, leap) { 0 } else if leap { 1 } else { 2 };
+    let month = div_floor(12 * (ordinal - 1 + correction) + 373, 367) as u32;
+    (month, (ordinal - days_before_month(month, leap)) as u32)
+}
+
+fn to_i32(v: i64) -> Option<i32> {
+    if i32::min_value() as i64 <= v && v <= i32::max_value() as i64 {
+        Some(v as i32)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::{NaiveDate, Months, MIN_DATE, MAX_DATE};
+    use oldtime::Duration;
+    use Datelike;
+    use super::{Calendar, Gregorian, Julian};
+
+    #[test]
+    fn test_julian_ymd() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        for &((y, m, d), date) in &[((1, 1, 1), ymd(0, 12, 30)),
+                                    ((1582, 10, 4), ymd(1582, 10, 14)),
+                                    ((1582, 10, 5), ymd(1582, 10, 15)),
+                                    ((1900, 2, 29), ymd(1900, 3, 13)),
+                                    ((2000, 1, 1), ymd(2000, 1, 14)),
+                                    ((0, 2, 29), ymd(0, 2, 27)),
+                                    ((-4712, 1, 1), ymd(-4713, 11, 24))] {
+            assert_eq!(Julian.ymd_opt(y, m, d), Some(date));
+            assert_eq!(Julian.to_ymd(&date), (y, m, d));
+        }
+
+        assert_eq!(Julian.ymd_opt(2018, 2, 29), None);
+        assert_eq!(Julian.ymd_opt(2018, 4, 31), None);
+        assert_eq!(Julian.ymd_opt(2018, 13, 1), None);
+        assert_eq!(Julian.ymd_opt(2018, 1, 0), None);
+        assert_eq!(Julian.ymd_opt(i32::max_value(), 1, 1), None);
+    }
+
+    #[test]
+    fn test_calendar_round_trip() {
+        fn check<C: Calendar>(cal: C) {
+            let mut date = NaiveDate::from_ymd(-1, 1, 1);
+            let mut prev = cal.to_ymd(&date);
+            while date < NaiveDate::from_ymd(2401, 1, 1) {
+                date = date + Duration::days(1);
+                let (y, m, d) = cal.to_ymd(&date);
+                assert!((y, m, d) > prev, "{:?} should follow {:?}", (y, m, d), prev);
+                assert_eq!(cal.ymd_opt(y, m, d), Some(date));
+                prev = (y, m, d);
+            }
+
+            for &date in &[MIN_DATE, MAX_DATE] {
+                let (y, m, d) = cal.to_ymd(&date);
+                assert_eq!(cal.ymd_opt(y, m, d), Some(date));
+            }
+            for &fixed in &[i32::min_value(), -1, 0, 1, i32::max_value()] {
+                let (y, m, d) = cal.from_fixed(fixed);
+                assert_eq!(cal.to_fixed(y, m, d), Some(fixed));
+            }
+        }
+
+        check(Gregorian);
+        check(Julian);
+    }
+
+    #[test]
+    fn test_gregorian() {
+        let mut date = NaiveDate::from_ymd(-401, 1, 1);
+        while date < NaiveDate::from_ymd(2401, 1, 1) {
+            assert_eq!(Gregorian.to_ymd(&date), (date.year(), date.month(), date.day()));
+            date = date + Duration::days(1);
+        }
+        assert_eq!(Gregorian.to_ymd(&MIN_DATE), (MIN_DATE.year(), 1, 1));
+        assert_eq!(Gregorian.to_ymd(&MAX_DATE), (MAX_DATE.year(), 12, 31));
+    }
+
+    #[test]
+    fn test_calendar_add_months() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let d = ymd(2014, 1, 31);
+        assert_eq!(Gregorian.checked_add_months(&d, 0), Some(d));
+        assert_eq!(Gregorian.checked_add_months(&d, 1), Some(ymd(2014, 2, 28)));
+        assert_eq!(Gregorian.checked_add_months(&d, 25), Some(ymd(2016, 2, 29)));
+        assert_eq!(Gregorian.checked_add_months(&d, -2), Some(ymd(2013, 11, 30)));
+        assert_eq!(Gregorian.checked_add_months(&d, -25), Some(ymd(2011, 12, 31)));
+        // consistent with `NaiveDate::checked_{add,sub}_months`
+        for &months in &[-25, -12, -1, 0, 1, 11, 12, 100] {
+            let expected = if months < 0 {
+                d.checked_sub_months(Months(-months as u32))
+            } else {
+                d.checked_add_months(Months(months as u32))
+            };
+            assert_eq!(Gregorian.checked_add_months(&d, months), expected);
+        }
+
+        let d = Julian.ymd(2100, 1, 31);
+        assert_eq!(Julian.checked_add_months(&d, 1), Some(Julian.ymd(2100, 2, 29)));
+        assert_eq!(Julian.checked_add_months(&d, -1), Some(Julian.ymd(2099, 12, 31)));
+
+        assert_eq!(Gregorian.checked_add_months(&MAX_DATE, 1), None);
+        assert_eq!(Gregorian.checked_add_months(&MIN_DATE, -1), None);
+        assert_eq!(Julian.checked_add_months(&MAX_DATE, i32::max_value()), None);
+        assert_eq!(Julian.checked_add_months(&MIN_DATE, i32::min_value()), None);
+    }
+}
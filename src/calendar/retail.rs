@@ -0,0 +1,412 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The 52-53 week retail calendars, with quarters divided in the 4-4-5, 4-5-4 or 5-4-4 pattern.
+//!
+//! A retail year always ends on the same day of the week, either the last one of a month
+//! or the one nearest to the end of a month, so every year has 52 or 53 whole weeks.
+//! Each quarter has 13 weeks divided into three periods following the pattern,
+//! and the 53rd week, if any, is added to the last period of the year.
+//!
+//! ~~~~
+//! use chrono::NaiveDate;
+//! use chrono::calendar::retail::RetailCalendar;
+//!
+//! // the 4-5-4 calendar of the National Retail Federation
+//! let nrf = RetailCalendar::nrf();
+//! let d = NaiveDate::from_ymd(2019, 12, 25);
+//! assert_eq!(nrf.retail_year(&d), 2019);
+//! assert_eq!((nrf.quarter(&d), nrf.period(&d), nrf.week(&d)), (4, 11, 47));
+//! assert_eq!(nrf.year_start(2019), Some(NaiveDate::from_ymd(2019, 2, 3)));
+//! assert_eq!(nrf.period_end(2019, 11), Some(NaiveDate::from_ymd(2020, 1, 4)));
+//! assert_eq!(nrf.weeks_in_year(2023), Some(53));
+//! ~~~~
+
+use std::cmp;
+
+use div::mod_floor;
+use naive::NaiveDate;
+use Weekday;
+use Datelike;
+use super::{Calendar, Gregorian};
+
+/// The numbers of weeks in the three periods of every quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    /// 4, 4 and 5 weeks.
+    FourFourFive,
+    /// 4, 5 and 4 weeks.
+    FourFiveFour,
+    /// 5, 4 and 4 weeks.
+    FiveFourFour,
+}
+
+impl Pattern {
+    /// Returns the number of weeks in each period of a quarter.
+    fn weeks(&self) -> [u32; 3] {
+        match *self {
+            Pattern::FourFourFive => [4, 4, 5],
+            Pattern::FourFiveFour => [4, 5, 4],
+            Pattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// How the last day of a retail year is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearEnd {
+    /// The last given day of the week in the month.
+    LastOfMonth,
+    /// The given day of the week nearest to the end of the month,
+    /// which can fall on the first three days of the next month.
+    NearestEndOfMonth,
+}
+
+/// A 52-53 week retail calendar.
+///
+/// A retail year is named after the calendar year of the month in which it ends
+/// (even when the last day falls on the next month),
+/// which can be changed with [`named_by_start_year`](#method.named_by_start_year).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetailCalendar {
+    pattern: Pattern,
+    end_month: u32,
+    end_weekday: Weekday,
+    year_end: YearEnd,
+    by_start_year: bool,
+}
+
+impl RetailCalendar {
+    /// Makes a new retail calendar whose years end on given day of the week
+    /// at the end of given month.
+    ///
+    /// Panics if the month is invalid.
+    pub fn new(pattern: Pattern, end_month: u32, end_weekday: Weekday,
+               year_end: YearEnd) -> RetailCalendar {
+        RetailCalendar::new_opt(pattern, end_month, end_weekday, year_end)
+            .expect("invalid retail year end")
+    }
+
+    /// Makes a new retail calendar whose years end on given day of the week
+    /// at the end of given month.
+    ///
+    /// Returns `None` if the month is invalid.
+    pub fn new_opt(pattern: Pattern, end_month: u32, end_weekday: Weekday,
+                   year_end: YearEnd) -> Option<RetailCalendar> {
+        if end_month < 1 || end_month > 12 {
+            return None;
+        }
+        Some(RetailCalendar { pattern: pattern, end_month: end_month, end_weekday: end_weekday,
+                              year_end: year_end, by_start_year: false })
+    }
+
+    /// Makes the 4-5-4 calendar of the National Retail Federation.
+    ///
+    /// Its years end on the Saturday nearest to the end of January
+    /// and are named after the calendar year in which they start.
+    pub fn nrf() -> RetailCalendar {
+        RetailCalendar::new(Pattern::FourFiveFour, 1, Weekday::Sat, YearEnd::NearestEndOfMonth)
+            .named_by_start_year()
+    }
+
+    /// Names the retail years after the calendar year of the month following the year end.
+    pub fn named_by_start_year(self) -> RetailCalendar {
+        RetailCalendar { by_start_year: true, ..self }
+    }
+
+    /// Returns the pattern of the periods in every quarter.
+    pub fn pattern(&self) -> Pattern {
+        self.pattern
+    }
+
+    /// Returns the month at the end of which the retail years end.
+    pub fn end_month(&self) -> u32 {
+        self.end_month
+    }
+
+    /// Returns the day of the week on which the retail years end.
+    pub fn end_weekday(&self) -> Weekday {
+        self.end_weekday
+    }
+
+    /// Returns the difference between the year of `end_month` and the name of a retail year.
+    fn name_offset(&self) -> i32 {
+        if self.by_start_year && self.end_month != 12 { 1 } else { 0 }
+    }
+
+    /// Returns the fixed day number of the last day of the retail year
+    /// ending around `end_month` of given calendar year.
+    fn end_fixed(&self, year: i32) -> Option<i64> {
+        let days = try_opt!(Gregorian.days_in_month(year, self.end_month));
+        let last = try_opt!(Gregorian.to_fixed(year, self.end_month, days)) as i64;
+        // the fixed day 1 is a Monday
+        let back = mod_floor(last - 1 - self.end_weekday.num_days_from_monday() as i64, 7);
+        match self.year_end {
+            YearEnd::LastOfMonth => Some(last - back),
+            YearEnd::NearestEndOfMonth if back <= 3 => Some(last - back),
+            YearEnd::NearestEndOfMonth => Some(last - back + 7),
+        }
+    }
+
+    /// Returns the fixed day numbers of the first and last days of given retail year.
+    fn bounds(&self, retail_year: i32) -> Option<(i64, i64)> {
+        let year = try_opt!(retail_year.checked_add(self.name_offset()));
+        let start = try_opt!(self.end_fixed(try_opt!(year.checked_sub(1)))) + 1;
+        Some((start, try_opt!(self.end_fixed(year))))
+    }
+
+    /// Returns the retail year and the number of days since its first day for given date.
+    fn locate(&self, date: &NaiveDate) -> (i32, u32) {
+        let fixed = date.num_days_from_ce() as i64;
+        let mut year = date.year();
+        if fixed > self.end_fixed(year).unwrap() {
+            year += 1;
+        } else if fixed <= self.end_fixed(year - 1).unwrap() {
+            year -= 1;
+        }
+        let start = self.end_fixed(year - 1).unwrap() + 1;
+        (year - self.name_offset(), (fixed - start) as u32)
+    }
+
+    /// Returns the retail year of given date.
+    pub fn retail_year(&self, date: &NaiveDate) -> i32 {
+        self.locate(date).0
+    }
+
+    /// Returns the week of the retail year of given date, from 1 to 53.
+    pub fn week(&self, date: &NaiveDate) -> u32 {
+        self.locate(date).1 / 7 + 1
+    }
+
+    /// Returns the quarter of the retail year of given date, from 1 to 4.
+    pub fn quarter(&self, date: &NaiveDate) -> u32 {
+        (self.period(date) - 1) / 3 + 1
+    }
+
+    /// Returns the period of the retail year of given date, from 1 to 12.
+    pub fn period(&self, date: &NaiveDate) -> u32 {
+        // the 53rd week belongs to the 12th period
+        let week0 = cmp::min(self.week(date) - 1, 51);
+        let weeks = self.pattern.weeks();
+        let mut period = week0 / 13 * 3 + 1;
+        let mut week0 = week0 % 13;
+        for &n in &weeks[..2] {
+            if week0 < n {
+                break;
+            }
+            week0 -= n;
+            period += 1;
+        }
+        period
+    }
+
+    /// Returns the number of weeks in given retail year, either 52 or 53.
+    ///
+    /// Returns `None` on the out-of-range year.
+    pub fn weeks_in_year(&self, retail_year: i32) -> Option<u32> {
+        let (start, end) = try_opt!(self.bounds(retail_year));
+        Some(((end - start + 1) / 7) as u32)
+    }
+
+    /// Returns the first day of given retail year.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn year_start(&self, retail_year: i32) -> Option<NaiveDate> {
+        from_fixed(try_opt!(self.bounds(retail_year)).0)
+    }
+
+    /// Returns the last day of given retail year.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn year_end(&self, retail_year: i32) -> Option<NaiveDate> {
+        from_fixed(try_opt!(self.bounds(retail_year)).1)
+    }
+
+    /// Returns the first day of given period (from 1 to 12) of given retail year.
+    ///
+    /// Returns `None` on the invalid period or the out-of-range date.
+    pub fn period_start(&self, retail_year: i32, period: u32) -> Option<NaiveDate> {
+        if period < 1 || period > 12 {
+            return None;
+        }
+        let (start, _) = try_opt!(self.bounds(retail_year));
+        let weeks = self.pattern.weeks();
+        let weeks_before = (period - 1) / 3 * 13 +
+                           weeks[..((period - 1) % 3) as usize].iter().sum::<u32>();
+        from_fixed(start + 7 * weeks_before as i64)
+    }
+
+    /// Returns the last day of given period (from 1 to 12) of given retail year.
+    ///
+    /// Returns `None` on the invalid period or the out-of-range date.
+    pub fn period_end(&self, retail_year: i32, period: u32) -> Option<NaiveDate> {
+        if period < 1 || period > 12 {
+            return None;
+        }
+        if period == 12 {
+            return self.year_end(retail_year);
+        }
+        self.period_start(retail_year, period + 1).and_then(|d| d.pred_opt())
+    }
+
+    /// Returns an iterator over the last days of the periods, starting from the end of
+    /// the period containing given date.
+    ///
+    /// The iterator ends at the last period ending within the range of `NaiveDate`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::calendar::retail::RetailCalendar;
+    ///
+    /// let nrf = RetailCalendar::nrf();
+    /// let ends: Vec<_> = nrf.period_ends(&NaiveDate::from_ymd(2019, 12, 25)).take(3).collect();
+    /// assert_eq!(ends, [NaiveDate::from_ymd(2020, 1, 4), NaiveDate::from_ymd(2020, 2, 1),
+    ///                   NaiveDate::from_ymd(2020, 2, 29)]);
+    /// ~~~~
+    pub fn period_ends(&self, date: &NaiveDate) -> PeriodEnds {
+        PeriodEnds { calendar: *self, year: self.retail_year(date), period: self.period(date) }
+    }
+}
+
+fn from_fixed(fixed: i64) -> Option<NaiveDate> {
+    if fixed < i32::min_value() as i64 || fixed > i32::max_value() as i64 {
+        return None;
+    }
+    NaiveDate::from_num_days_from_ce_opt(fixed as i32)
+}
+
+/// An iterator over the last days of the periods of
+/// [`RetailCalendar`](./struct.RetailCalendar.html).
+#[derive(Debug, Clone)]
+pub struct PeriodEnds {
+    calendar: RetailCalendar,
+    year: i32,
+    period: u32,
+}
+
+impl Iterator for PeriodEnds {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let end = try_opt!(self.calendar.period_end(self.year, self.period));
+        if self.period == 12 {
+            self.year += 1;
+            self.period = 1;
+        } else {
+            self.period += 1;
+        }
+        Some(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+    use oldtime::Duration;
+    use {Weekday, Datelike};
+    use super::{RetailCalendar, Pattern, YearEnd};
+
+    #[test]
+    fn test_retail_calendar() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        let nrf = RetailCalendar::nrf();
+        assert_eq!(nrf.year_start(2023), Some(ymd(2023, 1, 29)));
+        assert_eq!(nrf.year_end(2023), Some(ymd(2024, 2, 3)));
+        assert_eq!(nrf.weeks_in_year(2022), Some(52));
+        assert_eq!(nrf.weeks_in_year(2023), Some(53));
+        assert_eq!(nrf.retail_year(&ymd(2024, 2, 3)), 2023);
+        assert_eq!(nrf.retail_year(&ymd(2024, 2, 4)), 2024);
+        assert_eq!(nrf.week(&ymd(2024, 2, 3)), 53);
+        assert_eq!(nrf.period(&ymd(2024, 2, 3)), 12);
+        assert_eq!(nrf.period_start(2023, 2), Some(ymd(2023, 2, 26)));
+        assert_eq!(nrf.period_end(2023, 2), Some(ymd(2023, 4, 1)));
+        assert_eq!(nrf.period_start(2023, 12), Some(ymd(2023, 12, 31)));
+        assert_eq!(nrf.period_end(2023, 12), Some(ymd(2024, 2, 3)));
+        assert_eq!(nrf.period_start(2023, 0), None);
+        assert_eq!(nrf.period_end(2023, 13), None);
+
+        // periods and quarters follow the pattern through the year
+        for &(pattern, weeks) in &[(Pattern::FourFourFive, [4, 4, 5]),
+                                   (Pattern::FourFiveFour, [4, 5, 4]),
+                                   (Pattern::FiveFourFour, [5, 4, 4])] {
+            let cal = RetailCalendar::new(pattern, 12, Weekday::Sun, YearEnd::LastOfMonth);
+            assert_eq!(cal.pattern(), pattern);
+            let mut date = cal.year_start(2020).unwrap();
+            for period in 1..13 {
+                assert_eq!(cal.period_start(2020, period), Some(date));
+                let n = weeks[(period as usize - 1) % 3];
+                for _ in 0..n * 7 {
+                    assert_eq!(cal.retail_year(&date), 2020);
+                    assert_eq!(cal.period(&date), period);
+                    assert_eq!(cal.quarter(&date), (period - 1) / 3 + 1);
+                    date = date.succ();
+                }
+                assert_eq!(cal.period_end(2020, period), Some(date.pred()));
+            }
+            assert_eq!(cal.year_start(2021), Some(date));
+        }
+
+        let last = RetailCalendar::new(Pattern::FourFourFive, 12, Weekday::Fri,
+                                       YearEnd::LastOfMonth);
+        assert_eq!((last.end_month(), last.end_weekday()), (12, Weekday::Fri));
+        assert_eq!(last.year_end(2020), Some(ymd(2020, 12, 25)));
+        assert_eq!(last.year_end(2021), Some(ymd(2021, 12, 31)));
+        assert_eq!(last.named_by_start_year().year_end(2021), Some(ymd(2021, 12, 31)));
+        assert_eq!(last.week(&ymd(2021, 12, 31)), 53);
+
+        // the year can end in early January of the next year
+        let nearest = RetailCalendar::new(Pattern::FourFourFive, 12, Weekday::Sat,
+                                          YearEnd::NearestEndOfMonth);
+        assert_eq!(nearest.year_end(2022), Some(ymd(2022, 12, 31)));
+        assert_eq!(nearest.year_end(2021), Some(ymd(2022, 1, 1)));
+        assert_eq!(nearest.retail_year(&ymd(2022, 1, 1)), 2021);
+        assert_eq!(nearest.week(&ymd(2022, 1, 2)), 1);
+
+        // every year has whole weeks and follows the previous year
+        let mut date = ymd(1999, 1, 1);
+        let mut prev = (nrf.retail_year(&date), nrf.week(&date));
+        while date < ymd(2031, 1, 1) {
+            date = date + Duration::days(1);
+            let year = nrf.retail_year(&date);
+            let week = nrf.week(&date);
+            if year == prev.0 {
+                assert!(week == prev.1 || week == prev.1 + 1);
+            } else {
+                assert_eq!((year, week), (prev.0 + 1, 1));
+                assert_eq!(nrf.weeks_in_year(prev.0), Some(prev.1));
+            }
+            prev = (year, week);
+        }
+
+        assert_eq!(RetailCalendar::new_opt(Pattern::FourFourFive, 0, Weekday::Sat,
+                                           YearEnd::LastOfMonth), None);
+        assert_eq!(RetailCalendar::new_opt(Pattern::FourFourFive, 13, Weekday::Sat,
+                                           YearEnd::LastOfMonth), None);
+        assert!(nrf.week(&MIN_DATE) >= 1);
+        assert!(nrf.week(&MAX_DATE) <= 53);
+    }
+
+    #[test]
+    fn test_retail_period_ends() {
+        let nrf = RetailCalendar::nrf();
+        let ends: Vec<_> = nrf.period_ends(&NaiveDate::from_ymd(2023, 12, 1)).take(4).collect();
+        assert_eq!(ends, [NaiveDate::from_ymd(2023, 12, 30), NaiveDate::from_ymd(2024, 2, 3),
+                          NaiveDate::from_ymd(2024, 3, 2), NaiveDate::from_ymd(2024, 4, 6)]);
+
+        let mut prev = None;
+        for end in nrf.period_ends(&NaiveDate::from_ymd(2000, 1, 1)).take(12 * 30) {
+            assert_eq!(nrf.period_end(nrf.retail_year(&end), nrf.period(&end)), Some(end));
+            if let Some(prev) = prev {
+                assert!(end > prev);
+            }
+            prev = Some(end);
+        }
+
+        let near_max = NaiveDate::from_ymd(MAX_DATE.year() - 1, 1, 1);
+        assert!(nrf.period_ends(&near_max).all(|d| d <= MAX_DATE));
+        assert!(nrf.period_ends(&near_max).count() < 26);
+    }
+}
@@ -10,9 +10,10 @@ use oldtime::Duration as OldDuration;
 
 use {Weekday, Datelike};
 use offset::{TimeZone, Utc};
-use naive::{self, NaiveDate, NaiveTime, IsoWeek};
+use naive::{self, NaiveDate, NaiveTime, IsoWeek, Months, Days};
 use DateTime;
 use format::{Item, DelayedFormat, StrftimeItems};
+#[cfg(feature = "locales")] use format::Locale;
 
 /// ISO 8601 calendar date with time zone.
 ///
@@ -219,6 +220,40 @@ impl<Tz: TimeZone> Date<Tz> {
         Some(Date { date: date, offset: self.offset })
     }
 
+    /// Adds given number of months to the current date,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting date is not unique in the time zone.
+    pub fn checked_add_months(self, months: Months) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_add_months(months))
+    }
+
+    /// Subtracts given number of months from the current date,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting date is not unique in the time zone.
+    pub fn checked_sub_months(self, months: Months) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_sub_months(months))
+    }
+
+    /// Adds given number of days to the current date.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting date is not unique in the time zone.
+    pub fn checked_add_days(self, days: Days) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_add_days(days))
+    }
+
+    /// Subtracts given number of days from the current date.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting date is not unique in the time zone.
+    pub fn checked_sub_days(self, days: Days) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_sub_days(days))
+    }
+
     /// Subtracts another `Date` from the current date.
     /// Returns a `Duration` of integral numbers.
     ///
@@ -267,6 +302,27 @@ impl<Tz: TimeZone> Date<Tz> where Tz::Offset: fmt::Display {
     pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_with_items(StrftimeItems::new(fmt))
     }
+
+    /// Formats the date with the specified formatting items,
+    /// printing the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized_with_items<'a, I>(&self, items: I, locale: Locale) -> DelayedFormat<I>
+            where I: Iterator<Item=Item<'a>> + Clone {
+        DelayedFormat::new_with_offset_and_locale(Some(self.naive_local()), None, &self.offset,
+                                                  items, locale)
+    }
+
+    /// Formats the date with the specified format string,
+    /// printing the month and weekday names in given locale.
+    /// See the [`format::strftime` module](./format/strftime/index.html)
+    /// on the supported escape sequences.
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized<'a>(&self, fmt: &'a str,
+                                locale: Locale) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_localized_with_items(StrftimeItems::new(fmt), locale)
+    }
 }
 
 impl<Tz: TimeZone> Datelike for Date<Tz> {
@@ -341,6 +397,42 @@ impl<Tz: TimeZone> hash::Hash for Date<Tz> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) { self.date.hash(state) }
 }
 
+impl<Tz: TimeZone> Add<Months> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Months) -> Date<Tz> {
+        self.checked_add_months(rhs).expect("`Date + Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Months> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> Date<Tz> {
+        self.checked_sub_months(rhs).expect("`Date - Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Add<Days> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Days) -> Date<Tz> {
+        self.checked_add_days(rhs).expect("`Date + Days` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Days> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> Date<Tz> {
+        self.checked_sub_days(rhs).expect("`Date - Days` out of range")
+    }
+}
+
 impl<Tz: TimeZone> Add<OldDuration> for Date<Tz> {
     type Output = Date<Tz>;
 
@@ -10,11 +10,45 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use oldtime::Duration as OldDuration;
 
 use {Weekday, Timelike, Datelike};
-use offset::{TimeZone, Offset, Utc, Local, FixedOffset};
-use naive::{NaiveTime, NaiveDateTime, IsoWeek};
+use offset::{TimeZone, Offset, Utc, Local, FixedOffset, Tai};
+use naive::{self, NaiveTime, NaiveDateTime, IsoWeek, Months, Days};
 use Date;
 use format::{Item, Numeric, Pad, Fixed};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
+use format::format_into;
+#[cfg(feature = "locales")] use format::Locale;
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
+
+/// Specific formatting options for seconds. This may be extended in the
+/// future, so exhaustive matching in external code is not recommended.
+///
+/// See the `DateTime::to_rfc3339_opts` function for usage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SecondsFormat {
+    /// Format whole seconds only, with no decimal point nor subseconds.
+    Secs,
+
+    /// Use fixed 3 subsecond digits. This corresponds to
+    /// [Fixed::Nanosecond3](format/enum.Fixed.html#variant.Nanosecond3).
+    Millis,
+
+    /// Use fixed 6 subsecond digits. This corresponds to
+    /// [Fixed::Nanosecond6](format/enum.Fixed.html#variant.Nanosecond6).
+    Micros,
+
+    /// Use fixed 9 subsecond digits. This corresponds to
+    /// [Fixed::Nanosecond9](format/enum.Fixed.html#variant.Nanosecond9).
+    Nanos,
+
+    /// Automatically select one of `Secs`, `Millis`, `Micros`, or `Nanos` to
+    /// display all available non-zero sub-second digits.  This corresponds to
+    /// [Fixed::Nanosecond](format/enum.Fixed.html#variant.Nanosecond).
+    AutoSi,
+
+    // Do not match against this.
+    #[doc(hidden)]
+    __NonExhaustive,
+}
 
 /// ISO 8601 combined date and time with time zone.
 ///
@@ -22,11 +56,17 @@ use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItem
 /// the general-purpose constructors are all via the methods on the
 /// [`TimeZone`](./offset/trait.TimeZone.html) implementations.
 #[derive(Clone)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct DateTime<Tz: TimeZone> {
     datetime: NaiveDateTime,
     offset: Tz::Offset,
 }
 
+/// The minimum possible `DateTime<Utc>`.
+pub const MIN_DATETIME: DateTime<Utc> = DateTime { datetime: naive::MIN_DATETIME, offset: Utc };
+/// The maximum possible `DateTime<Utc>`.
+pub const MAX_DATETIME: DateTime<Utc> = DateTime { datetime: naive::MAX_DATETIME, offset: Utc };
+
 impl<Tz: TimeZone> DateTime<Tz> {
     /// Makes a new `DateTime` with given *UTC* datetime and offset.
     /// The local datetime should be constructed via the `TimeZone` trait.
@@ -90,6 +130,66 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_millis()
     }
 
+    /// Returns the number of non-leap-microseconds since January 1, 1970 UTC
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::Utc;
+    /// use chrono::TimeZone;
+    ///
+    /// let dt = Utc.ymd(1970, 1, 1).and_hms_micro(0, 0, 1, 444);
+    /// assert_eq!(dt.timestamp_micros(), 1_000_444);
+    ///
+    /// let dt = Utc.ymd(2001, 9, 9).and_hms_micro(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_micros(), 1_000_000_000_000_555);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_micros(&self) -> i64 {
+        self.datetime.timestamp_micros()
+    }
+
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC
+    ///
+    /// Panics if the value does not fit in `i64`,
+    /// which happens for dates before about 1677 CE or after about 2262 CE.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::Utc;
+    /// use chrono::TimeZone;
+    ///
+    /// let dt = Utc.ymd(1970, 1, 1).and_hms_nano(0, 0, 1, 444);
+    /// assert_eq!(dt.timestamp_nanos(), 1_000_000_444);
+    ///
+    /// let dt = Utc.ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_nanos(), 1_000_000_000_000_000_555);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_nanos(&self) -> i64 {
+        self.datetime.timestamp_nanos()
+    }
+
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC
+    ///
+    /// Returns `None` if the value does not fit in `i64`,
+    /// which happens for dates before about 1677 CE or after about 2262 CE.
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        self.datetime.timestamp_nanos_opt()
+    }
+
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC
+    /// as `i128` which never overflows.
+    ///
+    /// This requires the `i128` feature.
+    #[cfg(feature = "i128")]
+    #[inline]
+    pub fn timestamp_nanos_i128(&self) -> i128 {
+        self.datetime.timestamp_nanos_i128()
+    }
+
     /// Returns the number of milliseconds since the last second boundary
     ///
     /// warning: in event of a leap second, this may exceed 999
@@ -157,6 +257,62 @@ impl<Tz: TimeZone> DateTime<Tz> {
         Some(DateTime { datetime: datetime, offset: self.offset })
     }
 
+    /// Adds given number of months to the current date and time in the local time,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting local time does not exist or is ambiguous in the time zone.
+    pub fn checked_add_months(self, months: Months) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_add_months(months))
+    }
+
+    /// Subtracts given number of months from the current date and time in the local time,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting local time does not exist or is ambiguous in the time zone.
+    pub fn checked_sub_months(self, months: Months) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_sub_months(months))
+    }
+
+    /// Adds given number of days to the current date and time in the local time.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting local time does not exist or is ambiguous in the time zone.
+    pub fn checked_add_days(self, days: Days) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_add_days(days))
+    }
+
+    /// Subtracts given number of days from the current date and time in the local time.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or the resulting local time does not exist or is ambiguous in the time zone.
+    pub fn checked_sub_days(self, days: Days) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_sub_days(days))
+    }
+
+    /// Adds given `Duration` to the current date and time,
+    /// clamping the result to the representable range in UTC on overflow.
+    ///
+    /// In UTC, the result is clamped to [`MIN_DATETIME`](./constant.MIN_DATETIME.html) or
+    /// [`MAX_DATETIME`](./constant.MAX_DATETIME.html).
+    #[inline]
+    pub fn saturating_add_signed(self, rhs: OldDuration) -> DateTime<Tz> {
+        let datetime = self.datetime.saturating_add_signed(rhs);
+        DateTime { datetime: datetime, offset: self.offset }
+    }
+
+    /// Subtracts given `Duration` from the current date and time,
+    /// clamping the result to the representable range in UTC on overflow.
+    ///
+    /// In UTC, the result is clamped to [`MIN_DATETIME`](./constant.MIN_DATETIME.html) or
+    /// [`MAX_DATETIME`](./constant.MAX_DATETIME.html).
+    #[inline]
+    pub fn saturating_sub_signed(self, rhs: OldDuration) -> DateTime<Tz> {
+        let datetime = self.datetime.saturating_sub_signed(rhs);
+        DateTime { datetime: datetime, offset: self.offset }
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     #[inline]
@@ -175,6 +331,60 @@ impl<Tz: TimeZone> DateTime<Tz> {
     pub fn naive_local(&self) -> NaiveDateTime {
         self.datetime + self.offset.fix()
     }
+
+    /// Returns the TAI (International Atomic Time) clock reading at this moment,
+    /// using the built-in table of leap seconds.
+    ///
+    /// TAI has no leap seconds, so subtracting two TAI clock readings
+    /// gives the exact interval between them even across leap seconds.
+    /// Use `with_timezone(&Tai::with_leap_seconds(..))` for a custom table of leap seconds.
+    ///
+    /// Panics if the resulting TAI clock reading is out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc, Duration};
+    ///
+    /// let before = Utc.ymd(2016, 12, 31).and_hms(23, 59, 59);
+    /// let after = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0);
+    /// assert_eq!(after.signed_duration_since(before), Duration::seconds(1));
+    /// assert_eq!(after.to_tai().signed_duration_since(before.to_tai()), Duration::seconds(2));
+    /// ~~~~
+    pub fn to_tai(&self) -> NaiveDateTime {
+        let offset = Tai::new().offset_from_utc_datetime(&self.datetime);
+        let nanos = self.datetime.nanosecond();
+        let secs = self.datetime.timestamp() + offset.tai_minus_utc() as i64 +
+                   (nanos / 1_000_000_000) as i64;
+        NaiveDateTime::from_timestamp_opt(secs, nanos % 1_000_000_000)
+            .expect("TAI clock reading out of range")
+    }
+}
+
+impl DateTime<Utc> {
+    /// Makes a new `DateTime<Utc>` from given TAI (International Atomic Time) clock reading,
+    /// using the built-in table of leap seconds.
+    /// This is the inverse of [`DateTime::to_tai`](#method.to_tai);
+    /// the TAI second corresponding to an inserted leap second
+    /// gives the leap second representation of UTC.
+    ///
+    /// Panics if the resulting date and time is out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    ///
+    /// let tai = NaiveDate::from_ymd(2017, 1, 1).and_hms_milli(0, 0, 36, 500);
+    /// let utc = Utc.ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500);
+    /// assert_eq!(DateTime::<Utc>::from_tai(&tai), utc);
+    /// assert_eq!(utc.to_tai(), tai);
+    /// ~~~~
+    pub fn from_tai(tai: &NaiveDateTime) -> DateTime<Utc> {
+        Tai::new().from_local_datetime(tai).single()
+            .expect("`DateTime::from_tai` out of range")
+            .with_timezone(&Utc)
+    }
 }
 
 /// Maps the local datetime to other datetime with given conversion function.
@@ -186,10 +396,30 @@ fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> Option<DateTime<Tz
 impl DateTime<FixedOffset> {
     /// Parses an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`,
     /// then returns a new `DateTime` with a parsed `FixedOffset`.
+    ///
+    /// The obsolete syntax of RFC 2822 is also accepted, including the comments,
+    /// the folding white space and the obsolete time zone names.
+    /// The zone `-0000` and the unknown zone names (including the military zones)
+    /// do not tell the local offset, so the resulting offset is `+00:00` as RFC 2822 suggests.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// assert_eq!(DateTime::parse_from_rfc2822("Tue, 1 Jul 2003 10:52:37 PDT (Pacific)"),
+    ///            Ok(FixedOffset::west(7 * 3600).ymd(2003, 7, 1).and_hms(10, 52, 37)));
+    /// assert_eq!(DateTime::parse_from_rfc2822("Tue, 1 Jul 2003 10:52:37 -0000"),
+    ///            Ok(FixedOffset::east(0).ymd(2003, 7, 1).and_hms(10, 52, 37)));
+    /// ~~~~
     pub fn parse_from_rfc2822(s: &str) -> ParseResult<DateTime<FixedOffset>> {
         const ITEMS: &'static [Item<'static>] = &[Item::Fixed(Fixed::RFC2822)];
         let mut parsed = Parsed::new();
         try!(parse(&mut parsed, s, ITEMS.iter().cloned()));
+        if parsed.offset.is_none() {
+            // RFC 2822 Section 3.3: the time is still in UT, only the local offset is unknown
+            try!(parsed.set_offset(0));
+        }
         parsed.to_datetime()
     }
 
@@ -216,6 +446,25 @@ impl DateTime<FixedOffset> {
         try!(parse(&mut parsed, s, StrftimeItems::new(fmt)));
         parsed.to_datetime()
     }
+
+    /// Same to [`DateTime::parse_from_str`](#method.parse_from_str) but
+    /// accepts common deviations from the format string, which are described in
+    /// [`Parsed::lenient`](./format/struct.Parsed.html#method.lenient).
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(0).ymd(2018, 8, 9).and_hms_milli(1, 2, 3, 450);
+    /// let fmt = "%Y-%m-%dT%H:%M:%S%.f%z";
+    /// assert_eq!(DateTime::parse_from_str_lenient("2018-08-09T01:02:03.450+0000", fmt), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_str_lenient("2018-8-9 1:2:3,45Z", fmt), Ok(dt));
+    /// assert!(DateTime::parse_from_str("2018-8-9 1:2:3,45Z", fmt).is_err());
+    /// ~~~~
+    pub fn parse_from_str_lenient(s: &str, fmt: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::lenient();
+        try!(parse(&mut parsed, s, StrftimeItems::new(fmt)));
+        parsed.to_datetime()
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz> where Tz::Offset: fmt::Display {
@@ -231,6 +480,114 @@ impl<Tz: TimeZone> DateTime<Tz> where Tz::Offset: fmt::Display {
         self.format_with_items(ITEMS.iter().cloned()).to_string()
     }
 
+    /// Returns an RFC 3339 and ISO 8601 date and time string with subseconds
+    /// formatted as per a `SecondsFormat`. If passed `use_z` true and the
+    /// timezone is UTC (offset 0), use 'Z', as per
+    /// [Fixed::TimezoneOffsetColonZ](format/enum.Fixed.html#variant.TimezoneOffsetColonZ).
+    /// If passed `use_z` false, use
+    /// [Fixed::TimezoneOffsetColon](format/enum.Fixed.html#variant.TimezoneOffsetColon).
+    ///
+    /// # Examples
+    ///
+    /// ~~~~
+    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, Utc};
+    /// let dt = Utc.ymd(2018, 1, 26).and_hms_micro(18, 30, 9, 453_829);
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, false),
+    ///            "2018-01-26T18:30:09.453+00:00");
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+    ///            "2018-01-26T18:30:09.453Z");
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true),
+    ///            "2018-01-26T18:30:09Z");
+    ///
+    /// let offset = FixedOffset::east(8 * 60 * 60);
+    /// let dt = offset.ymd(2018, 1, 26).and_hms_micro(10, 30, 9, 453_829);
+    /// assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true),
+    ///            "2018-01-26T10:30:09+08:00");
+    /// ~~~~
+    pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
+        use format::Numeric::*;
+        use format::Pad::Zero;
+        use SecondsFormat::*;
+
+        const PREFIX: &'static [Item<'static>] = &[
+            Item::Numeric(Year, Zero),
+            Item::Literal("-"),
+            Item::Numeric(Month, Zero),
+            Item::Literal("-"),
+            Item::Numeric(Day, Zero),
+            Item::Literal("T"),
+            Item::Numeric(Hour, Zero),
+            Item::Literal(":"),
+            Item::Numeric(Minute, Zero),
+            Item::Literal(":"),
+            Item::Numeric(Second, Zero),
+        ];
+
+        let ssitem = match secform {
+            Secs => None,
+            Millis => Some(Item::Fixed(Fixed::Nanosecond3)),
+            Micros => Some(Item::Fixed(Fixed::Nanosecond6)),
+            Nanos => Some(Item::Fixed(Fixed::Nanosecond9)),
+            AutoSi | __NonExhaustive => Some(Item::Fixed(Fixed::Nanosecond)),
+        };
+
+        let tzitem = Item::Fixed(
+            if use_z {
+                Fixed::TimezoneOffsetColonZ
+            } else {
+                Fixed::TimezoneOffsetColon
+            }
+        );
+
+        match ssitem {
+            None =>
+                self.format_with_items(
+                    PREFIX.iter().chain([tzitem].iter()).cloned()
+                ).to_string(),
+            Some(s) =>
+                self.format_with_items(
+                    PREFIX.iter().chain([s, tzitem].iter()).cloned()
+                ).to_string(),
+        }
+    }
+
+    /// Writes an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`
+    /// into given `fmt::Write`, without allocating.
+    pub fn write_rfc2822<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        const ITEMS: &'static [Item<'static>] = &[Item::Fixed(Fixed::RFC2822)];
+        self.write_unnamed(w, ITEMS)
+    }
+
+    /// Writes an RFC 3339 and ISO 8601 date and time string such as `1996-12-19T16:39:57-08:00`
+    /// into given `fmt::Write`, without allocating.
+    ///
+    /// ~~~~
+    /// use std::fmt::Write;
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let mut buf = String::with_capacity(64);
+    /// for secs in 0..3 {
+    ///     let dt = FixedOffset::east(9 * 3600).ymd(2018, 8, 9).and_hms(1, 2, secs);
+    ///     dt.write_rfc3339(&mut buf).unwrap();
+    ///     buf.push('\n');
+    /// }
+    /// assert_eq!(buf, "2018-08-09T01:02:00+09:00\n\
+    ///                  2018-08-09T01:02:01+09:00\n\
+    ///                  2018-08-09T01:02:02+09:00\n");
+    /// ~~~~
+    pub fn write_rfc3339<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        const ITEMS: &'static [Item<'static>] = &[Item::Fixed(Fixed::RFC3339)];
+        self.write_unnamed(w, ITEMS)
+    }
+
+    /// Writes given formatting items which never print the name of the offset.
+    fn write_unnamed<W: fmt::Write>(&self, w: &mut W, items: &[Item]) -> fmt::Result {
+        let local = self.naive_local();
+        // an empty `String` doesn't allocate
+        let off = (String::new(), self.offset.fix());
+        format_into(w, Some(&local.date()), Some(&local.time()), Some(&off), items.iter().cloned())
+    }
+
     /// Formats the combined date and time with the specified formatting items.
     #[inline]
     pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
@@ -246,6 +603,39 @@ impl<Tz: TimeZone> DateTime<Tz> where Tz::Offset: fmt::Display {
     pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_with_items(StrftimeItems::new(fmt))
     }
+
+    /// Formats the combined date and time with the specified formatting items,
+    /// printing the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized_with_items<'a, I>(&self, items: I, locale: Locale) -> DelayedFormat<I>
+            where I: Iterator<Item=Item<'a>> + Clone {
+        let local = self.naive_local();
+        DelayedFormat::new_with_offset_and_locale(Some(local.date()), Some(local.time()),
+                                                  &self.offset, items, locale)
+    }
+
+    /// Formats the combined date and time with the specified format string,
+    /// printing the month and weekday names in given locale.
+    /// See the [`format::strftime` module](./format/strftime/index.html)
+    /// on the supported escape sequences.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono::format::Locale;
+    ///
+    /// let dt = Utc.ymd(2017, 3, 5).and_hms(12, 0, 0);
+    /// assert_eq!(dt.format_localized("%A %-d %B %Y, %H:%M %Z", Locale::Dutch).to_string(),
+    ///            "zondag 5 maart 2017, 12:00 UTC");
+    /// ~~~~
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized<'a>(&self, fmt: &'a str,
+                                locale: Locale) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_localized_with_items(StrftimeItems::new(fmt), locale)
+    }
 }
 
 impl<Tz: TimeZone> Datelike for DateTime<Tz> {
@@ -356,6 +746,42 @@ impl<Tz: TimeZone> Add<OldDuration> for DateTime<Tz> {
     }
 }
 
+impl<Tz: TimeZone> Add<Months> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Months) -> DateTime<Tz> {
+        self.checked_add_months(rhs).expect("`DateTime + Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Months> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> DateTime<Tz> {
+        self.checked_sub_months(rhs).expect("`DateTime - Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Add<Days> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Days) -> DateTime<Tz> {
+        self.checked_add_days(rhs).expect("`DateTime + Days` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Days> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> DateTime<Tz> {
+        self.checked_sub_days(rhs).expect("`DateTime - Days` out of range")
+    }
+}
+
 impl<Tz: TimeZone> Sub<OldDuration> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
@@ -819,6 +1245,368 @@ pub mod serde {
         }
     }
 
+    /// Defines a module to serialize/deserialize `DateTime<Utc>` with given module
+    /// for `NaiveDateTime`, which is applied to the UTC date and time.
+    macro_rules! utc_module {
+        ($name:ident, $naive:ident, $doc:expr, $example:expr) => (
+            #[doc = $doc]
+            #[doc = ""]
+            #[doc = "Intended for use with `serde`'s `with` attribute."]
+            #[doc = ""]
+            #[doc = "# Example:"]
+            #[doc = ""]
+            #[doc = "```rust"]
+            #[doc = "# #[macro_use] extern crate serde_derive;"]
+            #[doc = "# extern crate serde_json;"]
+            #[doc = "# extern crate chrono;"]
+            #[doc = "# use chrono::{TimeZone, DateTime, Utc};"]
+            #[doc = $example]
+            #[doc = "```"]
+            pub mod $name {
+                use serdelib::{ser, de};
+
+                use {DateTime, Utc};
+                use naive::serde::$naive as naive;
+
+                /// Deserialize a `DateTime<Utc>`
+                pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+                    where D: de::Deserializer<'de>
+                {
+                    naive::deserialize(d).map(|dt| DateTime::from_utc(dt, Utc))
+                }
+
+                /// Serialize a `DateTime<Utc>`
+                pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+                    where S: ser::Serializer
+                {
+                    naive::serialize(&dt.naive_utc(), serializer)
+                }
+            }
+        )
+    }
+
+    /// Defines a module to serialize/deserialize `Option<DateTime<Utc>>` with given module
+    /// for `Option<NaiveDateTime>`, which is applied to the UTC date and time.
+    macro_rules! utc_option_module {
+        ($name:ident, $naive:ident, $doc:expr, $example:expr) => (
+            #[doc = $doc]
+            #[doc = ""]
+            #[doc = "Intended for use with `serde`'s `with` attribute."]
+            #[doc = "`None` is serialized as the unit value (`null` in JSON)."]
+            #[doc = ""]
+            #[doc = "# Example:"]
+            #[doc = ""]
+            #[doc = "```rust"]
+            #[doc = "# #[macro_use] extern crate serde_derive;"]
+            #[doc = "# extern crate serde_json;"]
+            #[doc = "# extern crate chrono;"]
+            #[doc = "# use chrono::{TimeZone, DateTime, Utc};"]
+            #[doc = $example]
+            #[doc = "```"]
+            pub mod $name {
+                use serdelib::{ser, de};
+
+                use {DateTime, Utc};
+                use naive::serde::$naive as naive;
+
+                /// Deserialize an `Option<DateTime<Utc>>`
+                pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+                    where D: de::Deserializer<'de>
+                {
+                    naive::deserialize(d).map(|opt| opt.map(|dt| DateTime::from_utc(dt, Utc)))
+                }
+
+                /// Serialize an `Option<DateTime<Utc>>`
+                pub fn serialize<S>(opt: &Option<DateTime<Utc>>,
+                                    serializer: S) -> Result<S::Ok, S::Error>
+                    where S: ser::Serializer
+                {
+                    naive::serialize(&opt.as_ref().map(|dt| dt.naive_utc()), serializer)
+                }
+            }
+        )
+    }
+
+    utc_module!(ts_milliseconds, ts_milliseconds,
+                "Ser/de to/from timestamps in milliseconds",
+                "use chrono::serde::ts_milliseconds;
+#[derive(Deserialize, Serialize)]
+struct S {
+    #[serde(with = \"ts_milliseconds\")]
+    time: DateTime<Utc>
+}
+
+# fn example() -> Result<S, serde_json::Error> {
+let time = Utc.ymd(2015, 5, 15).and_hms_milli(10, 0, 0, 123);
+let my_s = S { time: time };
+
+let as_string = serde_json::to_string(&my_s)?;
+assert_eq!(as_string, r#\"{\"time\":1431684000123}\"#);
+let my_s: S = serde_json::from_str(&as_string)?;
+assert_eq!(my_s.time, time);
+# Ok(my_s)
+# }
+# fn main() { example().unwrap(); }");
+
+    utc_module!(ts_nanoseconds, ts_nanoseconds,
+                "Ser/de to/from timestamps in nanoseconds
+
+The timestamps in nanoseconds only cover the years from 1677 to 2262,
+and serializing the `DateTime` outside of that range is an error.",
+                "use chrono::serde::ts_nanoseconds;
+#[derive(Deserialize, Serialize)]
+struct S {
+    #[serde(with = \"ts_nanoseconds\")]
+    time: DateTime<Utc>
+}
+
+# fn example() -> Result<S, serde_json::Error> {
+let time = Utc.ymd(2015, 5, 15).and_hms_nano(10, 0, 0, 123_456_789);
+let my_s = S { time: time };
+
+let as_string = serde_json::to_string(&my_s)?;
+assert_eq!(as_string, r#\"{\"time\":1431684000123456789}\"#);
+let my_s: S = serde_json::from_str(&as_string)?;
+assert_eq!(my_s.time, time);
+# Ok(my_s)
+# }
+# fn main() { example().unwrap(); }");
+
+    utc_option_module!(ts_seconds_option, ts_seconds_option,
+                       "Ser/de `Option<DateTime<Utc>>` to/from timestamps in seconds",
+                       "use chrono::serde::ts_seconds_option;
+#[derive(Deserialize, Serialize)]
+struct S {
+    #[serde(with = \"ts_seconds_option\")]
+    time: Option<DateTime<Utc>>
+}
+
+# fn example() -> Result<S, serde_json::Error> {
+let my_s = S { time: Some(Utc.ymd(2015, 5, 15).and_hms(10, 0, 0)) };
+let as_string = serde_json::to_string(&my_s)?;
+assert_eq!(as_string, r#\"{\"time\":1431684000}\"#);
+
+let my_s: S = serde_json::from_str(r#\"{\"time\":null}\"#)?;
+assert_eq!(my_s.time, None);
+# Ok(my_s)
+# }
+# fn main() { example().unwrap(); }");
+
+    utc_option_module!(ts_milliseconds_option, ts_milliseconds_option,
+                       "Ser/de `Option<DateTime<Utc>>` to/from timestamps in milliseconds",
+                       "use chrono::serde::ts_milliseconds_option;
+#[derive(Deserialize, Serialize)]
+struct S {
+    #[serde(with = \"ts_milliseconds_option\")]
+    time: Option<DateTime<Utc>>
+}
+
+# fn example() -> Result<S, serde_json::Error> {
+let my_s = S { time: Some(Utc.ymd(2015, 5, 15).and_hms_milli(10, 0, 0, 123)) };
+let as_string = serde_json::to_string(&my_s)?;
+assert_eq!(as_string, r#\"{\"time\":1431684000123}\"#);
+
+let my_s: S = serde_json::from_str(r#\"{\"time\":null}\"#)?;
+assert_eq!(my_s.time, None);
+# Ok(my_s)
+# }
+# fn main() { example().unwrap(); }");
+
+    utc_option_module!(ts_nanoseconds_option, ts_nanoseconds_option,
+                       "Ser/de `Option<DateTime<Utc>>` to/from timestamps in nanoseconds",
+                       "use chrono::serde::ts_nanoseconds_option;
+#[derive(Deserialize, Serialize)]
+struct S {
+    #[serde(with = \"ts_nanoseconds_option\")]
+    time: Option<DateTime<Utc>>
+}
+
+# fn example() -> Result<S, serde_json::Error> {
+let my_s = S { time: Some(Utc.ymd(2015, 5, 15).and_hms_nano(10, 0, 0, 123_456_789)) };
+let as_string = serde_json::to_string(&my_s)?;
+assert_eq!(as_string, r#\"{\"time\":1431684000123456789}\"#);
+
+let my_s: S = serde_json::from_str(r#\"{\"time\":null}\"#)?;
+assert_eq!(my_s.time, None);
+# Ok(my_s)
+# }
+# fn main() { example().unwrap(); }");
+
+    /// Ser/de to/from RFC 3339 strings
+    ///
+    /// Unlike the default `Serialize` implementation, which follows the `Debug` format,
+    /// this always writes a valid RFC 3339 string with a numeric offset.
+    /// Deserialization only accepts strings valid per RFC 3339, unlike the default
+    /// `Deserialize` implementation which uses the more lenient `FromStr`.
+    ///
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde_json;
+    /// # extern crate chrono;
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// use chrono::serde::rfc3339;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// # fn example() -> Result<S, serde_json::Error> {
+    /// let time = Utc.ymd(2015, 5, 15).and_hms(10, 0, 0);
+    /// let my_s = S { time: time };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15T10:00:00+00:00"}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
+    /// assert!(serde_json::from_str::<S>(r#"{"time":"2015-05-15 10:00:00Z"}"#).is_err());
+    /// # Ok(my_s)
+    /// # }
+    /// # fn main() { example().unwrap(); }
+    /// ```
+    pub mod rfc3339 {
+        use std::fmt;
+        use serdelib::{ser, de};
+
+        use {DateTime, Utc};
+
+        /// Deserialize a `DateTime<Utc>` from an RFC 3339 string
+        pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            d.deserialize_str(Rfc3339Visitor)
+        }
+
+        /// Serialize a `DateTime<Utc>` into an RFC 3339 string
+        pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.serialize_str(&dt.to_rfc3339())
+        }
+
+        struct Rfc3339Visitor;
+
+        impl<'de> de::Visitor<'de> for Rfc3339Visitor {
+            type Value = DateTime<Utc>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(formatter, "an RFC 3339 date and time string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<DateTime<Utc>, E>
+                where E: de::Error
+            {
+                DateTime::parse_from_rfc3339(value)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|err| E::custom(format!("{}", err)))
+            }
+        }
+    }
+
+    /// Ser/de to/from strings in a custom `strftime`-like format
+    ///
+    /// Since `serde`'s `with` attribute takes a module path rather than arguments,
+    /// the functions here take the format string as an extra argument;
+    /// wrap them in a small module that fixes the format to use them with `with`.
+    /// The date and time is formatted in UTC, and parsed as UTC
+    /// unless the format string contains an offset (`%z` etc.).
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde;
+    /// # extern crate serde_json;
+    /// # extern crate chrono;
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// mod my_format {
+    ///     use chrono::{DateTime, Utc};
+    ///     use serde::{Serializer, Deserializer};
+    ///
+    ///     const FORMAT: &'static str = "%Y-%m-%d %H:%M:%S";
+    ///
+    ///     pub fn serialize<S: Serializer>(dt: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error> {
+    ///         chrono::serde::custom::serialize(dt, FORMAT, s)
+    ///     }
+    ///
+    ///     pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime<Utc>, D::Error> {
+    ///         chrono::serde::custom::deserialize(d, FORMAT)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "my_format")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// # fn example() -> Result<S, serde_json::Error> {
+    /// let time = Utc.ymd(2015, 5, 15).and_hms(10, 0, 0);
+    /// let my_s = S { time: time };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-05-15 10:00:00"}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
+    /// # Ok(my_s)
+    /// # }
+    /// # fn main() { example().unwrap(); }
+    /// ```
+    pub mod custom {
+        use std::fmt;
+        use serdelib::{ser, de};
+
+        use {DateTime, Utc};
+        use format::{parse, Parsed, StrftimeItems};
+
+        /// Deserialize a `DateTime<Utc>` from a string in given format
+        pub fn deserialize<'de, D>(d: D, fmt: &str) -> Result<DateTime<Utc>, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            d.deserialize_str(CustomVisitor { fmt: fmt })
+        }
+
+        /// Serialize a `DateTime<Utc>` into a string in given format
+        pub fn serialize<S>(dt: &DateTime<Utc>, fmt: &str,
+                            serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.collect_str(&dt.format(fmt))
+        }
+
+        struct CustomVisitor<'a> {
+            fmt: &'a str,
+        }
+
+        impl<'a, 'de> de::Visitor<'de> for CustomVisitor<'a> {
+            type Value = DateTime<Utc>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(formatter, "a date and time string in the format {:?}", self.fmt)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<DateTime<Utc>, E>
+                where E: de::Error
+            {
+                let mut parsed = Parsed::new();
+                let result = parse(&mut parsed, value, StrftimeItems::new(self.fmt)).and_then(|_| {
+                    if parsed.offset.is_some() {
+                        parsed.to_datetime().map(|dt| dt.with_timezone(&Utc))
+                    } else {
+                        parsed.to_datetime_with_timezone(&Utc)
+                    }
+                });
+                result.map_err(|err| E::custom(format!("{}", err)))
+            }
+        }
+    }
+
     impl<Tz: TimeZone> ser::Serialize for DateTime<Tz> {
         /// Serialize into a rfc3339 time string
         ///
@@ -917,6 +1705,56 @@ pub mod serde {
                                    |input| self::serde_json::from_str(&input));
     }
 
+    #[test]
+    fn test_serde_with_modules() {
+        use self::serde_json::{Serializer, Deserializer};
+
+        fn to_json<F>(f: F) -> Option<String>
+            where F: FnOnce(&mut Serializer<Vec<u8>>) -> Result<(), self::serde_json::Error>
+        {
+            let mut ser = Serializer::new(Vec::new());
+            f(&mut ser).ok().map(|_| String::from_utf8(ser.into_inner()).unwrap())
+        }
+
+        let dt = Utc.ymd(2015, 5, 15).and_hms_nano(10, 0, 0, 123_456_789);
+        let far = Utc.ymd(2263, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(to_json(|s| ts_milliseconds::serialize(&dt, s)),
+                   Some("1431684000123".to_owned()));
+        assert_eq!(to_json(|s| ts_nanoseconds::serialize(&dt, s)),
+                   Some("1431684000123456789".to_owned()));
+        assert_eq!(to_json(|s| ts_nanoseconds::serialize(&far, s)), None);
+        assert_eq!(to_json(|s| rfc3339::serialize(&dt, s)),
+                   Some(r#""2015-05-15T10:00:00.123456789+00:00""#.to_owned()));
+        assert_eq!(to_json(|s| ts_seconds_option::serialize(&Some(dt), s)),
+                   Some("1431684000".to_owned()));
+        assert_eq!(to_json(|s| ts_milliseconds_option::serialize(&None, s)),
+                   Some("null".to_owned()));
+        assert_eq!(to_json(|s| custom::serialize(&dt, "%Y%m%d %H%M%S", s)),
+                   Some(r#""20150515 100000""#.to_owned()));
+
+        let de = |s| Deserializer::from_str(s);
+        assert_eq!(ts_milliseconds::deserialize(&mut de("1431684000123")).ok(),
+                   Some(Utc.ymd(2015, 5, 15).and_hms_milli(10, 0, 0, 123)));
+        assert_eq!(ts_milliseconds::deserialize(&mut de("-1")).ok(),
+                   Some(Utc.ymd(1969, 12, 31).and_hms_milli(23, 59, 59, 999)));
+        assert_eq!(ts_nanoseconds::deserialize(&mut de("1431684000123456789")).ok(), Some(dt));
+        assert_eq!(ts_nanoseconds::deserialize(&mut de("-1")).ok(),
+                   Some(Utc.ymd(1969, 12, 31).and_hms_nano(23, 59, 59, 999_999_999)));
+        assert_eq!(rfc3339::deserialize(&mut de(r#""2015-05-15T19:00:00.123456789+09:00""#))
+                       .ok(), Some(dt));
+        assert!(rfc3339::deserialize(&mut de(r#""2015-05-15 10:00:00""#)).is_err());
+        assert_eq!(ts_seconds_option::deserialize(&mut de("1431684000")).ok(),
+                   Some(Some(Utc.ymd(2015, 5, 15).and_hms(10, 0, 0))));
+        assert_eq!(ts_milliseconds_option::deserialize(&mut de("null")).ok(), Some(None));
+        assert!(ts_nanoseconds_option::deserialize(&mut de(r#""x""#)).is_err());
+        assert_eq!(custom::deserialize(&mut de(r#""20150515 100000""#), "%Y%m%d %H%M%S").ok(),
+                   Some(Utc.ymd(2015, 5, 15).and_hms(10, 0, 0)));
+        assert_eq!(custom::deserialize(&mut de(r#""20150515 190000 +0900""#),
+                                       "%Y%m%d %H%M%S %z").ok(),
+                   Some(Utc.ymd(2015, 5, 15).and_hms(10, 0, 0)));
+        assert!(custom::deserialize(&mut de(r#""2015-05-15""#), "%Y%m%d %H%M%S").is_err());
+    }
+
     #[test]
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
@@ -931,12 +1769,52 @@ pub mod serde {
     }
 }
 
+#[cfg(feature = "quickcheck")]
+mod arbitrary {
+    use super::DateTime;
+    use quickcheck::{Arbitrary, Gen};
+    use offset::{Utc, FixedOffset};
+    use naive::NaiveDateTime;
+    #[cfg(test)] use Datelike;
+
+    impl Arbitrary for DateTime<Utc> {
+        fn arbitrary(g: &mut Gen) -> DateTime<Utc> {
+            DateTime::from_utc(NaiveDateTime::arbitrary(g), Utc)
+        }
+    }
+
+    /// Generates an offset within a day in either direction with a minute precision,
+    /// and a datetime in that offset which does not overflow the supported range.
+    impl Arbitrary for DateTime<FixedOffset> {
+        fn arbitrary(g: &mut Gen) -> DateTime<FixedOffset> {
+            let offset = FixedOffset::east((i32::arbitrary(g) % (24 * 60)) * 60);
+            loop {
+                let utc = NaiveDateTime::arbitrary(g);
+                if utc.checked_add_signed(::oldtime::Duration::days(1)).is_some() &&
+                   utc.checked_sub_signed(::oldtime::Duration::days(1)).is_some() {
+                    return DateTime::from_utc(utc, offset);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    quickcheck! {
+        fn prop_rfc3339_roundtrip(dt: DateTime<FixedOffset>) -> bool {
+            // RFC 3339 is limited to four-digit years
+            dt.year() < 0 || dt.year() > 9999 ||
+                DateTime::parse_from_rfc3339(&dt.to_rfc3339()) == Ok(dt)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::DateTime;
-    use Datelike;
+    use std::i64;
+    use super::{DateTime, SecondsFormat};
+    use {Datelike, Timelike};
     use naive::{NaiveTime, NaiveDate};
-    use offset::{TimeZone, Utc, Local, FixedOffset};
+    use offset::{TimeZone, Utc, Local, FixedOffset, LocalResult};
     use oldtime::Duration;
     use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -1034,8 +1912,26 @@ mod tests {
         assert_eq!(EDT.ymd(2015, 2, 18).and_hms_micro(23, 59, 59, 1_234_567).to_rfc3339(),
                    "2015-02-18T23:59:60.234567+05:00");
 
+        let dt = Utc.ymd(2018, 1, 11).and_hms_nano(10, 5, 13, 84_660_000);
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, false), "2018-01-11T10:05:13+00:00");
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Secs, true), "2018-01-11T10:05:13Z");
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Millis, false),
+                   "2018-01-11T10:05:13.084+00:00");
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Micros, false),
+                   "2018-01-11T10:05:13.084660+00:00");
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::Nanos, true),
+                   "2018-01-11T10:05:13.084660000Z");
+        assert_eq!(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true), "2018-01-11T10:05:13.084660Z");
+        assert_eq!(EDT.ymd(2015, 2, 18).and_hms_micro(23, 59, 59, 1_234_567)
+                      .to_rfc3339_opts(SecondsFormat::Millis, true),
+                   "2015-02-18T23:59:60.234+05:00");
+
         assert_eq!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 +0000"),
                    Ok(FixedOffset::east(0).ymd(2015, 2, 18).and_hms(23, 16, 9)));
+        assert_eq!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 Z"),
+                   Ok(FixedOffset::east(0).ymd(2015, 2, 18).and_hms(23, 16, 9)));
+        assert_eq!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 18:16:09 EST (Eastern)"),
+                   Ok(FixedOffset::east(0).ymd(2015, 2, 18).and_hms(23, 16, 9)));
         assert_eq!(DateTime::parse_from_rfc3339("2015-02-18T23:16:09Z"),
                    Ok(FixedOffset::east(0).ymd(2015, 2, 18).and_hms(23, 16, 9)));
         assert_eq!(DateTime::parse_from_rfc2822("Wed, 18 Feb 2015 23:59:60 +0500"),
@@ -1074,6 +1970,108 @@ mod tests {
                    Ok(Utc.ymd(2013, 8, 9).and_hms(23, 54, 35)));
     }
 
+    #[test]
+    fn test_datetime_timestamp_subsec_units() {
+        let dt = FixedOffset::east(9 * 3600).ymd(2001, 9, 9).and_hms_nano(10, 46, 40, 555_666_777);
+        assert_eq!(dt.timestamp_millis(), 1_000_000_000_555);
+        assert_eq!(dt.timestamp_micros(), 1_000_000_000_555_666);
+        assert_eq!(dt.timestamp_nanos(), 1_000_000_000_555_666_777);
+
+        let tz = FixedOffset::east(9 * 3600);
+        assert_eq!(tz.timestamp_millis(1_000_000_000_555),
+                   dt.with_nanosecond(555_000_000).unwrap());
+        assert_eq!(tz.timestamp_micros(1_000_000_000_555_666),
+                   dt.with_nanosecond(555_666_000).unwrap());
+        assert_eq!(tz.timestamp_nanos(1_000_000_000_555_666_777), dt);
+        assert_eq!(Utc.timestamp_millis_opt(-1), Utc.timestamp_opt(-1, 999_000_000));
+        assert_eq!(Utc.timestamp_micros_opt(-1), Utc.timestamp_opt(-1, 999_999_000));
+        assert_eq!(Utc.timestamp_millis_opt(i64::MAX), LocalResult::None);
+        assert_eq!(Utc.timestamp_micros_opt(i64::MIN), LocalResult::None);
+    }
+
+    #[test]
+    fn test_datetime_timestamp_nanos_opt() {
+        assert_eq!(Utc.ymd(1677, 9, 21).and_hms_nano(0, 12, 43, 145_224_192).timestamp_nanos_opt(),
+                   Some(i64::MIN));
+        assert_eq!(Utc.ymd(1677, 9, 21).and_hms_nano(0, 12, 43, 145_224_191).timestamp_nanos_opt(),
+                   None);
+        assert_eq!(Utc.ymd(2262, 4, 11).and_hms_nano(23, 47, 16, 854_775_807).timestamp_nanos_opt(),
+                   Some(i64::MAX));
+        assert_eq!(Utc.ymd(2262, 4, 11).and_hms_nano(23, 47, 16, 854_775_808).timestamp_nanos_opt(),
+                   None);
+    }
+
+    #[test]
+    #[cfg(feature = "i128")]
+    fn test_datetime_timestamp_nanos_i128() {
+        use naive::{NaiveDateTime, MIN_DATE, MAX_DATE};
+
+        for dt in [MIN_DATE.and_hms(0, 0, 0), MAX_DATE.and_hms_nano(23, 59, 59, 1_999_999_999),
+                   NaiveDate::from_ymd(1969, 12, 31).and_hms_nano(23, 59, 59, 1),
+                   NaiveDate::from_ymd(2262, 4, 11).and_hms_nano(23, 47, 16, 854_775_807)].iter() {
+            let nanos = Utc.from_utc_datetime(dt).timestamp_nanos_i128();
+            if let Some(nanos_i64) = dt.timestamp_nanos_opt() {
+                assert_eq!(nanos, nanos_i64 as i128);
+            }
+            if dt.nanosecond() < 1_000_000_000 {
+                assert_eq!(NaiveDateTime::from_timestamp_nanos_i128_opt(nanos), Some(*dt));
+            }
+        }
+        assert_eq!(NaiveDateTime::from_timestamp_nanos_i128_opt(-1),
+                   Some(NaiveDate::from_ymd(1969, 12, 31).and_hms_nano(23, 59, 59, 999_999_999)));
+        assert_eq!(NaiveDateTime::from_timestamp_nanos_i128_opt(i128::min_value()), None);
+        assert_eq!(NaiveDateTime::from_timestamp_nanos_i128_opt(i128::max_value()), None);
+    }
+
+    #[test]
+    fn test_datetime_saturating_arithmetic() {
+        use super::{MIN_DATETIME, MAX_DATETIME};
+
+        let dt = FixedOffset::east(3600).ymd(2016, 7, 8).and_hms(3, 5, 7);
+        let min = MIN_DATETIME.with_timezone(&FixedOffset::east(3600));
+        let max = MAX_DATETIME.with_timezone(&FixedOffset::east(3600));
+        assert_eq!(dt.saturating_add_signed(Duration::hours(1)), dt + Duration::hours(1));
+        assert_eq!(dt.saturating_sub_signed(Duration::hours(1)), dt - Duration::hours(1));
+        assert_eq!(dt.saturating_add_signed(Duration::max_value()), max);
+        assert_eq!(dt.saturating_add_signed(Duration::min_value()), min);
+        assert_eq!(dt.saturating_sub_signed(Duration::max_value()), min);
+        assert_eq!(dt.saturating_sub_signed(Duration::min_value()), max);
+        assert_eq!(max.saturating_add_signed(Duration::nanoseconds(1)), max);
+        assert_eq!(min.saturating_sub_signed(Duration::nanoseconds(1)), min);
+        assert_eq!(max.saturating_sub_signed(Duration::nanoseconds(1)),
+                   max - Duration::nanoseconds(1));
+    }
+
+    #[test]
+    fn test_datetime_write_rfc() {
+        let dt = FixedOffset::west(5 * 3600).ymd(2015, 2, 18).and_hms_milli(23, 16, 9, 150);
+        let mut buf = String::new();
+        dt.write_rfc2822(&mut buf).unwrap();
+        assert_eq!(buf, dt.to_rfc2822());
+        buf.clear();
+        dt.write_rfc3339(&mut buf).unwrap();
+        assert_eq!(buf, dt.to_rfc3339());
+        buf.clear();
+        Utc.ymd(2015, 2, 18).and_hms(23, 16, 9).write_rfc3339(&mut buf).unwrap();
+        assert_eq!(buf, "2015-02-18T23:16:09+00:00");
+    }
+
+    #[test]
+    fn test_datetime_parse_from_str_lenient() {
+        let dt = FixedOffset::east(0).ymd(2014, 5, 7).and_hms_milli(12, 34, 56, 500);
+        let lenient = |s, fmt| DateTime::parse_from_str_lenient(s, fmt);
+        assert_eq!(lenient("2014-05-07T12:34:56.5+00:00", "%Y-%m-%dT%H:%M:%S%.f%:z"), Ok(dt));
+        assert_eq!(lenient("2014-05-07 12:34:56,5Z", "%Y-%m-%dT%H:%M:%S%.f%:z"), Ok(dt));
+        assert_eq!(lenient("2014-5-7t12:34:56.5z", "%Y-%m-%d %H:%M:%S%.f%z"), Ok(dt));
+        assert_eq!(lenient("2014-05-07 12:34:56,5Z", "%+"), Ok(dt));
+        assert_eq!(lenient("2014-05-07 Thu 12:34:56.5 +0000", "%F %a %T%.f %z").ok(),
+                   None); // Thursday is not mistaken for a separator and is inconsistent
+        assert_eq!(lenient("2014-05-08 Thu 12:34:56.5 +0000", "%F %a %T%.f %z"),
+                   Ok(dt + Duration::days(1)));
+        assert!(lenient("2014-05-07X12:34:56Z", "%Y-%m-%dT%H:%M:%S%z").is_err());
+        assert!(DateTime::parse_from_str("2014-05-07 12:34:56,5Z", "%+").is_err());
+    }
+
     #[test]
     fn test_datetime_format_with_local() {
         // if we are not around the year boundary, local and UTC date should have the same year
@@ -1134,4 +2132,45 @@ mod tests {
         assert_eq!(SystemTime::from(epoch.with_timezone(&FixedOffset::east(32400))), UNIX_EPOCH);
         assert_eq!(SystemTime::from(epoch.with_timezone(&FixedOffset::west(28800))), UNIX_EPOCH);
     }
+
+    #[test]
+    fn test_datetime_add_months_days() {
+        use super::{MIN_DATETIME, MAX_DATETIME};
+        use naive::{Months, Days};
+
+        let dt = Utc.ymd(2014, 1, 31).and_hms(10, 0, 0);
+        assert_eq!(dt + Months(1), Utc.ymd(2014, 2, 28).and_hms(10, 0, 0));
+        assert_eq!(dt - Months(2), Utc.ymd(2013, 11, 30).and_hms(10, 0, 0));
+        assert_eq!(dt + Days(1), Utc.ymd(2014, 2, 1).and_hms(10, 0, 0));
+        assert_eq!(dt - Days(31), Utc.ymd(2013, 12, 31).and_hms(10, 0, 0));
+        assert_eq!(dt.date() + Months(1), Utc.ymd(2014, 2, 28));
+        assert_eq!(dt.date() - Days(1), Utc.ymd(2014, 1, 30));
+
+        let edt = FixedOffset::west(4 * 3600);
+        let dt = edt.ymd(2016, 3, 31).and_hms(23, 0, 0);
+        assert_eq!(dt.checked_sub_months(Months(1)), Some(edt.ymd(2016, 2, 29).and_hms(23, 0, 0)));
+        assert_eq!(dt.checked_add_days(Days(1)), Some(edt.ymd(2016, 4, 1).and_hms(23, 0, 0)));
+        assert_eq!(MAX_DATETIME.checked_add_days(Days(1)), None);
+        assert_eq!(MIN_DATETIME.checked_sub_months(Months(1)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rkyv")]
+    fn test_rkyv_roundtrip() {
+        use rkyv::{self, Deserialize, Infallible};
+
+        let dt = Utc.ymd(2014, 7, 24).and_hms_nano(12, 34, 6, 1_000_000_123);
+        let bytes = rkyv::to_bytes::<_, 64>(&dt).unwrap();
+        let archived = unsafe { rkyv::archived_root::<DateTime<Utc>>(&bytes[..]) };
+        let decoded: DateTime<Utc> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(decoded, dt);
+
+        let edt = FixedOffset::west(4 * 3600);
+        let dt = edt.ymd(2016, 3, 31).and_hms(23, 0, 0);
+        let bytes = rkyv::to_bytes::<_, 64>(&dt).unwrap();
+        let archived = unsafe { rkyv::archived_root::<DateTime<FixedOffset>>(&bytes[..]) };
+        let decoded: DateTime<FixedOffset> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(decoded, dt);
+        assert_eq!(decoded.offset(), &edt);
+    }
 }
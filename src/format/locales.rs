@@ -0,0 +1,160 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Month and day of the week names, in English and optionally in other languages.
+
+/// Month and day of the week names in a language.
+#[derive(Debug)]
+pub struct Names {
+    pub short_months: [&'static str; 12],
+    pub long_months: [&'static str; 12],
+    pub short_weekdays: [&'static str; 7],
+    pub long_weekdays: [&'static str; 7],
+}
+
+pub static ENGLISH: Names = Names {
+    short_months: ["Jan", "Feb", "Mar", "Apr", "May", "Jun",
+                   "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+    long_months: ["January", "February", "March", "April", "May", "June",
+                  "July", "August", "September", "October", "November", "December"],
+    short_weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    long_weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday",
+                    "Friday", "Saturday", "Sunday"],
+};
+
+/// A language for the month and day of the week names printed by
+/// [`Fixed::ShortMonthName`](./enum.Fixed.html#variant.ShortMonthName) (`%b`),
+/// [`Fixed::LongMonthName`](./enum.Fixed.html#variant.LongMonthName) (`%B`),
+/// [`Fixed::ShortWeekdayName`](./enum.Fixed.html#variant.ShortWeekdayName) (`%a`) and
+/// [`Fixed::LongWeekdayName`](./enum.Fixed.html#variant.LongWeekdayName) (`%A`).
+///
+/// Other items, including AM/PM and the RFC 2822 and RFC 3339 syntaxes, are not affected.
+/// The names are the same as those in the GNU C library for the main locale of each language.
+#[cfg(feature = "locales")]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+pub enum Locale {
+    /// English, which is the default for the non-localized formatting.
+    English,
+    /// German (`de_DE`).
+    German,
+    /// French (`fr_FR`).
+    French,
+    /// Spanish (`es_ES`).
+    Spanish,
+    /// Italian (`it_IT`).
+    Italian,
+    /// Portuguese (`pt_PT`).
+    Portuguese,
+    /// Dutch (`nl_NL`).
+    Dutch,
+}
+
+/// Returns the names for given locale.
+#[cfg(feature = "locales")]
+pub fn names(locale: Locale) -> &'static Names {
+    match locale {
+        Locale::English => &ENGLISH,
+        Locale::German => &GERMAN,
+        Locale::French => &FRENCH,
+        Locale::Spanish => &SPANISH,
+        Locale::Italian => &ITALIAN,
+        Locale::Portuguese => &PORTUGUESE,
+        Locale::Dutch => &DUTCH,
+    }
+}
+
+#[cfg(feature = "locales")]
+static GERMAN: Names = Names {
+    short_months: ["Jan", "Feb", "Mär", "Apr", "Mai", "Jun",
+                   "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"],
+    long_months: ["Januar", "Februar", "März", "April", "Mai", "Juni",
+                  "Juli", "August", "September", "Oktober", "November", "Dezember"],
+    short_weekdays: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+    long_weekdays: ["Montag", "Dienstag", "Mittwoch", "Donnerstag",
+                    "Freitag", "Samstag", "Sonntag"],
+};
+
+#[cfg(feature = "locales")]
+static FRENCH: Names = Names {
+    short_months: ["janv.", "févr.", "mars", "avril", "mai", "juin",
+                   "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+    long_months: ["janvier", "février", "mars", "avril", "mai", "juin",
+                  "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+    short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    long_weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+};
+
+#[cfg(feature = "locales")]
+static SPANISH: Names = Names {
+    short_months: ["ene", "feb", "mar", "abr", "may", "jun",
+                   "jul", "ago", "sep", "oct", "nov", "dic"],
+    long_months: ["enero", "febrero", "marzo", "abril", "mayo", "junio",
+                  "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+    short_weekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    long_weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+};
+
+#[cfg(feature = "locales")]
+static ITALIAN: Names = Names {
+    short_months: ["gen", "feb", "mar", "apr", "mag", "giu",
+                   "lug", "ago", "set", "ott", "nov", "dic"],
+    long_months: ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno",
+                  "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"],
+    short_weekdays: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    long_weekdays: ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+};
+
+#[cfg(feature = "locales")]
+static PORTUGUESE: Names = Names {
+    short_months: ["jan", "fev", "mar", "abr", "mai", "jun",
+                   "jul", "ago", "set", "out", "nov", "dez"],
+    long_months: ["janeiro", "fevereiro", "março", "abril", "maio", "junho",
+                  "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"],
+    short_weekdays: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+    long_weekdays: ["segunda", "terça", "quarta", "quinta", "sexta", "sábado", "domingo"],
+};
+
+#[cfg(feature = "locales")]
+static DUTCH: Names = Names {
+    short_months: ["jan", "feb", "mrt", "apr", "mei", "jun",
+                   "jul", "aug", "sep", "okt", "nov", "dec"],
+    long_months: ["januari", "februari", "maart", "april", "mei", "juni",
+                  "juli", "augustus", "september", "oktober", "november", "december"],
+    short_weekdays: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    long_weekdays: ["maandag", "dinsdag", "woensdag", "donderdag",
+                    "vrijdag", "zaterdag", "zondag"],
+};
+
+#[cfg(all(test, feature = "locales"))]
+mod tests {
+    use super::Locale;
+    use format::{Item, Fixed};
+    use offset::{TimeZone, FixedOffset};
+
+    #[test]
+    fn test_format_localized() {
+        let dt = FixedOffset::east(3600).ymd(2018, 8, 14).and_hms(9, 5, 0);
+        let cases = [
+            (Locale::English, "Tue, Tuesday, Aug, August"),
+            (Locale::German, "Di, Dienstag, Aug, August"),
+            (Locale::French, "mar., mardi, août, août"),
+            (Locale::Spanish, "mar, martes, ago, agosto"),
+            (Locale::Italian, "mar, martedì, ago, agosto"),
+            (Locale::Portuguese, "ter, terça, ago, agosto"),
+            (Locale::Dutch, "di, dinsdag, aug, augustus"),
+        ];
+        for &(locale, expected) in cases.iter() {
+            assert_eq!(dt.format_localized("%a, %A, %b, %B", locale).to_string(), expected);
+            assert_eq!(dt.naive_local().format_localized("%a, %A, %b, %B", locale).to_string(),
+                       expected);
+            assert_eq!(dt.date().format_localized("%a, %A, %b, %B", locale).to_string(),
+                       expected);
+
+            // other items are not localized
+            assert_eq!(dt.format_localized("%p %H:%M %z", locale).to_string(), "AM 09:05 +0100");
+            let items = [Item::Fixed(Fixed::RFC2822)];
+            assert_eq!(dt.format_localized_with_items(items.iter().cloned(), locale).to_string(),
+                       "Tue, 14 Aug 2018 09:05:00 +0100");
+        }
+    }
+}
@@ -23,14 +23,13 @@ use {Datelike, Timelike, Weekday, ParseWeekdayError};
 use div::{div_floor, mod_floor};
 use offset::{Offset, FixedOffset};
 use naive::{NaiveDate, NaiveTime};
+use DateTime;
 
 pub use self::strftime::StrftimeItems;
 pub use self::parsed::Parsed;
 pub use self::parse::parse;
-
-/// An unhabitated type used for `InternalNumeric` and `InternalFixed` below.
-#[derive(Clone, PartialEq, Eq)]
-enum Void {}
+#[cfg(feature = "locales")] pub use self::locales::Locale;
+use self::locales::Names;
 
 /// Padding characters for numeric items.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -112,23 +111,9 @@ pub enum Numeric {
 }
 
 /// An opaque type representing numeric item types for internal uses only.
+#[derive(Clone, PartialEq, Eq)]
 pub struct InternalNumeric {
-    _dummy: Void,
-}
-
-impl Clone for InternalNumeric {
-    fn clone(&self) -> Self {
-        match self._dummy {}
-    }
-}
-
-impl PartialEq for InternalNumeric {
-    fn eq(&self, _other: &InternalNumeric) -> bool {
-        match self._dummy {}
-    }
-}
-
-impl Eq for InternalNumeric {
+    val: InternalNumericKind,
 }
 
 impl fmt::Debug for InternalNumeric {
@@ -137,6 +122,15 @@ impl fmt::Debug for InternalNumeric {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum InternalNumericKind {
+    /// Quarter of the year (FW=PW=1).
+    Quarter,
+    /// Week of the month, where the week 1 contains the first day of the month
+    /// and weeks start on Monday (FW=PW=1).
+    WeekOfMonth,
+}
+
 /// Fixed-format item types.
 ///
 /// They have their own rules of formatting and parsing.
@@ -213,30 +207,33 @@ pub enum Fixed {
 }
 
 /// An opaque type representing fixed-format item types for internal uses only.
+#[derive(Clone, PartialEq, Eq)]
 pub struct InternalFixed {
-    _dummy: Void,
+    val: InternalFixedKind,
 }
 
-impl Clone for InternalFixed {
-    fn clone(&self) -> Self {
-        match self._dummy {}
+impl fmt::Debug for InternalFixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<InternalFixed>")
     }
 }
 
-impl PartialEq for InternalFixed {
-    fn eq(&self, _other: &InternalFixed) -> bool {
-        match self._dummy {}
-    }
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum InternalFixedKind {
+    /// Same to [`RFC3339`](./enum.Fixed.html#variant.RFC3339) but parses without any leniency,
+    /// and rejects the inputs which cannot be represented exactly.
+    RFC3339Strict,
+    /// ISO 8601 date and time syntax, which allows the basic format, week dates and ordinal dates.
+    /// Prints the same to [`RFC3339`](./enum.Fixed.html#variant.RFC3339).
+    ISO8601,
 }
 
-impl Eq for InternalFixed {
-}
+/// The strict RFC 3339 syntax, used by `DateTime::parse_from_rfc3339_strict`.
+const RFC3339_STRICT: Fixed =
+    Fixed::Internal(InternalFixed { val: InternalFixedKind::RFC3339Strict });
 
-impl fmt::Debug for InternalFixed {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "<InternalFixed>")
-    }
-}
+/// The ISO 8601 syntax, used by `DateTime::parse_from_iso8601`.
+const ISO8601: Fixed = Fixed::Internal(InternalFixed { val: InternalFixedKind::ISO8601 });
 
 /// A single formatting item. This is used for both formatting and parsing.
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -264,6 +261,11 @@ macro_rules! num  { ($x:ident) => (Item::Numeric(Numeric::$x, Pad::None)) }
 macro_rules! num0 { ($x:ident) => (Item::Numeric(Numeric::$x, Pad::Zero)) }
 macro_rules! nums { ($x:ident) => (Item::Numeric(Numeric::$x, Pad::Space)) }
 macro_rules! fix  { ($x:ident) => (Item::Fixed(Fixed::$x)) }
+macro_rules! internal_num {
+    ($x:ident) => (Item::Numeric(Numeric::Internal(InternalNumeric {
+        val: InternalNumericKind::$x
+    }), Pad::None))
+}
 
 /// An error from the `parse` function.
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
@@ -337,17 +339,35 @@ const BAD_FORMAT:   ParseError = ParseError(ParseErrorKind::BadFormat);
 pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Option<&NaiveTime>,
                      off: Option<&(String, FixedOffset)>, items: I) -> fmt::Result
         where I: Iterator<Item=Item<'a>> {
-    // full and abbreviated month and weekday names
-    static SHORT_MONTHS: [&'static str; 12] =
-        ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
-    static LONG_MONTHS: [&'static str; 12] =
-        ["January", "February", "March", "April", "May", "June",
-         "July", "August", "September", "October", "November", "December"];
-    static SHORT_WEEKDAYS: [&'static str; 7] =
-        ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-    static LONG_WEEKDAYS: [&'static str; 7] =
-        ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+    format_with_names(w, date, time, off, items, &locales::ENGLISH)
+}
 
+/// Same to `format` but writes into any `fmt::Write`, like a `String` or a fixed-size buffer.
+///
+/// The formatting itself doesn't allocate,
+/// so this can be used for formatting many values into a reused buffer.
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::format::{format_into, StrftimeItems};
+///
+/// let dt = NaiveDate::from_ymd(2018, 8, 9).and_hms(1, 2, 3);
+/// let mut buf = String::with_capacity(32);
+/// format_into(&mut buf, Some(&dt.date()), Some(&dt.time()), None,
+///             StrftimeItems::new("%Y-%m-%d %H:%M:%S")).unwrap();
+/// assert_eq!(buf, "2018-08-09 01:02:03");
+/// ~~~~
+pub fn format_into<'a, W, I>(w: &mut W, date: Option<&NaiveDate>, time: Option<&NaiveTime>,
+                             off: Option<&(String, FixedOffset)>, items: I) -> fmt::Result
+        where W: fmt::Write, I: Iterator<Item=Item<'a>> {
+    format_with_names(w, date, time, off, items, &locales::ENGLISH)
+}
+
+/// Same to `format_into` but prints the month and weekday names in given language.
+fn format_with_names<'a, W, I>(w: &mut W, date: Option<&NaiveDate>,
+                               time: Option<&NaiveTime>, off: Option<&(String, FixedOffset)>,
+                               items: I, names: &Names) -> fmt::Result
+        where W: fmt::Write, I: Iterator<Item=Item<'a>> {
     for item in items {
         match item {
             Item::Literal(s) | Item::Space(s) => try!(write!(w, "{}", s)),
@@ -390,8 +410,10 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                         (_, _, _) => None
                     }),
 
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
+                    Internal(InternalNumeric { val: InternalNumericKind::Quarter }) =>
+                                      (1, date.map(|d| d.quarter() as i64)),
+                    Internal(InternalNumeric { val: InternalNumericKind::WeekOfMonth }) =>
+                                      (1, date.map(|d| d.week_of_month() as i64)),
                 };
 
                 if let Some(v) = v {
@@ -419,8 +441,9 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
 
                 /// Prints an offset from UTC in the format of `+HHMM` or `+HH:MM`.
                 /// `Z` instead of `+00[:]00` is allowed when `allow_zulu` is true.
-                fn write_local_minus_utc(w: &mut fmt::Formatter, off: FixedOffset,
-                                         allow_zulu: bool, use_colon: bool) -> fmt::Result {
+                fn write_local_minus_utc<W: fmt::Write>(w: &mut W, off: FixedOffset,
+                                                        allow_zulu: bool,
+                                                        use_colon: bool) -> fmt::Result {
                     let off = off.local_minus_utc();
                     if !allow_zulu || off != 0 {
                         let (sign, off) = if off < 0 {('-', -off)} else {('+', off)};
@@ -436,15 +459,15 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
 
                 let ret = match spec {
                     ShortMonthName =>
-                        date.map(|d| write!(w, "{}", SHORT_MONTHS[d.month0() as usize])),
+                        date.map(|d| write!(w, "{}", names.short_months[d.month0() as usize])),
                     LongMonthName =>
-                        date.map(|d| write!(w, "{}", LONG_MONTHS[d.month0() as usize])),
+                        date.map(|d| write!(w, "{}", names.long_months[d.month0() as usize])),
                     ShortWeekdayName =>
                         date.map(|d| write!(w, "{}",
-                            SHORT_WEEKDAYS[d.weekday().num_days_from_monday() as usize])),
+                            names.short_weekdays[d.weekday().num_days_from_monday() as usize])),
                     LongWeekdayName =>
                         date.map(|d| write!(w, "{}",
-                            LONG_WEEKDAYS[d.weekday().num_days_from_monday() as usize])),
+                            names.long_weekdays[d.weekday().num_days_from_monday() as usize])),
                     LowerAmPm =>
                         time.map(|t| write!(w, "{}", if t.hour12().0 {"pm"} else {"am"})),
                     UpperAmPm =>
@@ -487,18 +510,24 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                         off.map(|&(_, off)| write_local_minus_utc(w, off, false, false)),
                     TimezoneOffsetZ =>
                         off.map(|&(_, off)| write_local_minus_utc(w, off, true, false)),
-                    RFC2822 => // same to `%a, %e %b %Y %H:%M:%S %z`
+                    RFC2822 => // same to `%a, %e %b %Y %H:%M:%S %z` (always in English)
                         if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
+                            let english = &locales::ENGLISH;
                             let sec = t.second() + t.nanosecond() / 1_000_000_000;
                             try!(write!(w, "{}, {:2} {} {:04} {:02}:{:02}:{:02} ",
-                                        SHORT_WEEKDAYS[d.weekday().num_days_from_monday() as usize],
-                                        d.day(), SHORT_MONTHS[d.month0() as usize], d.year(),
+                                        english.short_weekdays[d.weekday().num_days_from_monday()
+                                                               as usize],
+                                        d.day(), english.short_months[d.month0() as usize],
+                                        d.year(),
                                         t.hour(), t.minute(), sec));
                             Some(write_local_minus_utc(w, off, false, false))
                         } else {
                             None
                         },
-                    RFC3339 => // same to `%Y-%m-%dT%H:%M:%S%.f%:z`
+                    RFC3339 |
+                    Internal(InternalFixed { val: InternalFixedKind::RFC3339Strict }) |
+                    Internal(InternalFixed { val: InternalFixedKind::ISO8601 }) =>
+                        // same to `%Y-%m-%dT%H:%M:%S%.f%:z`
                         if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
                             // reuse `Debug` impls which already print ISO 8601 format.
                             // this is faster in this way.
@@ -507,9 +536,6 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
                         } else {
                             None
                         },
-
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
                 };
 
                 match ret {
@@ -526,6 +552,7 @@ pub fn format<'a, I>(w: &mut fmt::Formatter, date: Option<&NaiveDate>, time: Opt
 }
 
 mod parsed;
+mod locales;
 
 // due to the size of parsing routines, they are in separate modules.
 mod scan;
@@ -545,12 +572,15 @@ pub struct DelayedFormat<I> {
     off: Option<(String, FixedOffset)>,
     /// An iterator returning formatting items.
     items: I,
+    /// Month and weekday names.
+    names: &'static Names,
 }
 
 impl<'a, I: Iterator<Item=Item<'a>> + Clone> DelayedFormat<I> {
     /// Makes a new `DelayedFormat` value out of local date and time.
     pub fn new(date: Option<NaiveDate>, time: Option<NaiveTime>, items: I) -> DelayedFormat<I> {
-        DelayedFormat { date: date, time: time, off: None, items: items }
+        DelayedFormat { date: date, time: time, off: None, items: items,
+                        names: &locales::ENGLISH }
     }
 
     /// Makes a new `DelayedFormat` value out of local date and time and UTC offset.
@@ -558,13 +588,36 @@ impl<'a, I: Iterator<Item=Item<'a>> + Clone> DelayedFormat<I> {
                                 offset: &Off, items: I) -> DelayedFormat<I>
             where Off: Offset + fmt::Display {
         let name_and_diff = (offset.to_string(), offset.fix());
-        DelayedFormat { date: date, time: time, off: Some(name_and_diff), items: items }
+        DelayedFormat { date: date, time: time, off: Some(name_and_diff), items: items,
+                        names: &locales::ENGLISH }
+    }
+
+    /// Makes a new `DelayedFormat` value out of local date and time,
+    /// which prints the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    pub fn new_with_locale(date: Option<NaiveDate>, time: Option<NaiveTime>, items: I,
+                           locale: Locale) -> DelayedFormat<I> {
+        DelayedFormat { date: date, time: time, off: None, items: items,
+                        names: locales::names(locale) }
+    }
+
+    /// Makes a new `DelayedFormat` value out of local date and time and UTC offset,
+    /// which prints the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    pub fn new_with_offset_and_locale<Off>(date: Option<NaiveDate>, time: Option<NaiveTime>,
+                                           offset: &Off, items: I,
+                                           locale: Locale) -> DelayedFormat<I>
+            where Off: Offset + fmt::Display {
+        let name_and_diff = (offset.to_string(), offset.fix());
+        DelayedFormat { date: date, time: time, off: Some(name_and_diff), items: items,
+                        names: locales::names(locale) }
     }
 }
 
 impl<'a, I: Iterator<Item=Item<'a>> + Clone> fmt::Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        format(f, self.date.as_ref(), self.time.as_ref(), self.off.as_ref(), self.items.clone())
+        format_with_names(f, self.date.as_ref(), self.time.as_ref(), self.off.as_ref(),
+                          self.items.clone(), self.names)
     }
 }
 
@@ -606,3 +659,59 @@ impl FromStr for Weekday {
     }
 }
 
+// these methods are here only because they need the private `RFC3339_STRICT` and `ISO8601` items
+
+impl DateTime<FixedOffset> {
+    /// Parses an RFC 3339 date and time string such as `1996-12-19T16:39:57-08:00`
+    /// strictly, then returns a new `DateTime` with a parsed `FixedOffset`.
+    ///
+    /// Unlike [`parse_from_rfc3339`](#method.parse_from_rfc3339), this rejects the inputs
+    /// which would not round-trip: more than 9 fractional digits of seconds,
+    /// which would be otherwise truncated, and the unknown local offset `-00:00`,
+    /// which would be otherwise indistinguishable from `+00:00`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// assert_eq!(DateTime::parse_from_rfc3339_strict("2015-02-18T23:16:09.153-08:00"),
+    ///            Ok(FixedOffset::west(8 * 3600).ymd(2015, 2, 18).and_hms_milli(23, 16, 9, 153)));
+    /// assert!(DateTime::parse_from_rfc3339_strict("2015-02-18T23:16:09.1234567891Z").is_err());
+    /// assert!(DateTime::parse_from_rfc3339_strict("2015-02-18T23:16:09-00:00").is_err());
+    /// ~~~~
+    pub fn parse_from_rfc3339_strict(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        const ITEMS: &'static [Item<'static>] = &[Item::Fixed(RFC3339_STRICT)];
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, s, ITEMS.iter().cloned()));
+        parsed.to_datetime()
+    }
+
+    /// Parses an ISO 8601 date and time string, then returns a new `DateTime`
+    /// with a parsed `FixedOffset`.
+    ///
+    /// In addition to the RFC 3339 syntax, this accepts the basic format
+    /// without separators (`20150218T231609Z`), the week dates (`2015-W08-3T23:16:09Z`),
+    /// the ordinal dates (`2015-049T23:16:09Z`), the omitted minutes or seconds (`T23Z`),
+    /// a comma for the fractional seconds and the offset with hours only (`+09`).
+    /// The date, time and offset should be consistently in either the basic or extended format.
+    /// The offset cannot be omitted, since `DateTime` requires it.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(0).ymd(2023, 3, 14).and_hms(10, 15, 30);
+    /// assert_eq!(DateTime::parse_from_iso8601("20230314T101530Z"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_iso8601("2023-W11-2T10:15:30Z"), Ok(dt));
+    /// assert_eq!(DateTime::parse_from_iso8601("2023-073T10:15:30+00"), Ok(dt));
+    /// assert!(DateTime::parse_from_rfc3339("20230314T101530Z").is_err());
+    /// ~~~~
+    pub fn parse_from_iso8601(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        const ITEMS: &'static [Item<'static>] = &[Item::Fixed(ISO8601)];
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, s, ITEMS.iter().cloned()));
+        parsed.to_datetime()
+    }
+}
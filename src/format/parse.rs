@@ -5,11 +5,13 @@
 //! Date and time parsing routines.
 
 use std::usize;
+use num::traits::FromPrimitive;
 
 use Weekday;
 
 use super::scan;
-use super::{Parsed, ParseResult, Item};
+use super::{Parsed, ParseResult, Item, InternalNumeric, InternalNumericKind};
+use super::{InternalFixed, InternalFixedKind};
 use super::{OUT_OF_RANGE, INVALID, TOO_SHORT, TOO_LONG, BAD_FORMAT};
 
 fn set_weekday_with_num_days_from_sunday(p: &mut Parsed, v: i64) -> ParseResult<()> {
@@ -59,11 +61,10 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     //
     // - quoted characters can be in any mixture of lower and upper cases.
     //
-    // - we do not recognize a folding white space (FWS) or comment (CFWS).
-    //   for our purposes, instead, we accept any sequence of Unicode
-    //   white space characters (denoted here to `S`). any actual RFC 2822
-    //   parser is expected to parse FWS and/or CFWS themselves and replace
-    //   it with a single SP (`%x20`); this is legitimate.
+    // - we accept any sequence of Unicode white space characters (denoted here to `S`)
+    //   in place of a folding white space (FWS), which is a superset of it.
+    //   comments (CFWS) are also accepted wherever the white space is allowed,
+    //   as well as after the zone, and they are ignored.
     //
     // - two-digit year < 50 should be interpreted by adding 2000.
     //   two-digit year >= 50 or three-digit year should be interpreted
@@ -82,19 +83,26 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     //   since we do not directly go to a `DateTime` so one can recover
     //   the offset information from `Parsed` anyway.
 
-    s = s.trim_left();
+    // a mandatory white space, which can be also a comment
+    fn space(s: &str) -> ParseResult<&str> {
+        let s_ = try!(scan::comment_or_space(s));
+        if s_.len() < s.len() { Ok(s_) } else { scan::space(s) }
+    }
+
+    s = try!(scan::comment_or_space(s));
 
     if let Ok((s_, weekday)) = scan::short_weekday(s) {
+        let s_ = try!(scan::comment_or_space(s_));
         if !s_.starts_with(',') { return Err(INVALID); }
         s = &s_[1..];
         try!(parsed.set_weekday(weekday));
     }
 
-    s = s.trim_left();
+    s = try!(scan::comment_or_space(s));
     try!(parsed.set_day(try_consume!(scan::number(s, 1, 2))));
-    s = try!(scan::space(s)); // mandatory
+    s = try!(space(s)); // mandatory
     try!(parsed.set_month(1 + try_consume!(scan::short_month0(s)) as i64));
-    s = try!(scan::space(s)); // mandatory
+    s = try!(space(s)); // mandatory
 
     // distinguish two- and three-digit years from four-digit years
     let prevlen = s.len();
@@ -108,24 +116,28 @@ fn parse_rfc2822<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     }
     try!(parsed.set_year(year));
 
-    s = try!(scan::space(s)); // mandatory
+    s = try!(space(s)); // mandatory
     try!(parsed.set_hour(try_consume!(scan::number(s, 2, 2))));
-    s = try!(scan::char(s.trim_left(), b':')).trim_left(); // *S ":" *S
+    s = try!(scan::char(try!(scan::comment_or_space(s)), b':')); // *S ":" *S
+    s = try!(scan::comment_or_space(s));
     try!(parsed.set_minute(try_consume!(scan::number(s, 2, 2))));
-    if let Ok(s_) = scan::char(s.trim_left(), b':') { // [ ":" *S 2DIGIT ]
+    if let Ok(s_) = scan::char(try!(scan::comment_or_space(s)), b':') { // [ ":" *S 2DIGIT ]
+        let s_ = try!(scan::comment_or_space(s_));
         try!(parsed.set_second(try_consume!(scan::number(s_, 2, 2))));
     }
 
-    s = try!(scan::space(s)); // mandatory
+    s = try!(space(s)); // mandatory
     if let Some(offset) = try_consume!(scan::timezone_offset_2822(s)) {
         // only set the offset when it is definitely known (i.e. not `-0000`)
         try!(parsed.set_offset(offset as i64));
     }
+    s = try!(scan::comment_or_space(s));
 
     Ok((s, ()))
 }
 
-fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str,
+                     strict: bool) -> ParseResult<(&'a str, ())> {
     macro_rules! try_consume {
         ($e:expr) => ({ let (s_, v) = try!($e); s = s_; v })
     }
@@ -156,6 +168,11 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     // - unlike RFC 2822, the valid offset ranges from -23:59 to +23:59.
     //   note that this restriction is unique to RFC 3339 and not ISO 8601.
     //   since this is not a typical Chrono behavior, we check it earlier.
+    //
+    // - in the strict mode, the lenient parsing is never allowed,
+    //   and the inputs which do not round-trip are rejected: more than 9 fractional digits
+    //   (which would be truncated) and `-00:00` (the unknown offset in RFC 3339 Section 4.3,
+    //   which would be indistinguishable from `+00:00`).
 
     try!(parsed.set_year(try_consume!(scan::number(s, 4, 4))));
     s = try!(scan::char(s, b'-'));
@@ -163,8 +180,10 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     s = try!(scan::char(s, b'-'));
     try!(parsed.set_day(try_consume!(scan::number(s, 2, 2))));
 
+    let lenient = parsed.is_lenient() && !strict;
     s = match s.as_bytes().first() {
         Some(&b't') | Some(&b'T') => &s[1..],
+        Some(&b' ') if lenient => &s[1..],
         Some(_) => return Err(INVALID),
         None => return Err(TOO_SHORT),
     };
@@ -174,11 +193,14 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     try!(parsed.set_minute(try_consume!(scan::number(s, 2, 2))));
     s = try!(scan::char(s, b':'));
     try!(parsed.set_second(try_consume!(scan::number(s, 2, 2))));
-    if s.starts_with('.') {
+    if s.starts_with('.') || (lenient && s.starts_with(',')) {
+        let prevlen = s.len();
         let nanosecond = try_consume!(scan::nanosecond(&s[1..]));
+        if strict && prevlen - s.len() > 10 { return Err(OUT_OF_RANGE); }
         try!(parsed.set_nanosecond(nanosecond));
     }
 
+    if strict && s.starts_with("-00:00") { return Err(INVALID); }
     let offset = try_consume!(scan::timezone_offset_zulu(s, |s| scan::char(s, b':')));
     if offset <= -86400 || offset >= 86400 { return Err(OUT_OF_RANGE); }
     try!(parsed.set_offset(offset as i64));
@@ -186,6 +208,143 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     Ok((s, ()))
 }
 
+fn parse_iso8601<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a str, ())> {
+    macro_rules! try_consume {
+        ($e:expr) => ({ let (s_, v) = try!($e); s = s_; v })
+    }
+
+    // an adapted ISO 8601 syntax for the complete representations of date and time,
+    // in either the extended format (with separators) or the basic format (without):
+    //
+    // year          = 4DIGIT
+    // calendar-date = year "-" 2DIGIT "-" 2DIGIT / year 2DIGIT 2DIGIT
+    // week-date     = year "-W" 2DIGIT "-" 1DIGIT / year "W" 2DIGIT 1DIGIT
+    // ordinal-date  = year "-" 3DIGIT / year 3DIGIT
+    // time          = 2DIGIT [":" 2DIGIT [":" 2DIGIT [fraction]]] /
+    //                 2DIGIT [2DIGIT [2DIGIT [fraction]]]
+    // fraction      = ("." / ",") 1*DIGIT
+    // offset        = "Z" / ("+" / "-") 2DIGIT [":" 2DIGIT] /
+    //                 "Z" / ("+" / "-") 2DIGIT [2DIGIT]
+    // date-time     = (calendar-date / week-date / ordinal-date) "T" time [offset]
+    //
+    // some notes:
+    //
+    // - quoted characters can be in any mixture of lower and upper cases.
+    //
+    // - the date determines the format, and the time and offset should be in the same format.
+    //   for example, `2015-W08-3T23:16:09Z` and `2015W083T231609Z` are fine,
+    //   but `2015-W08-3T231609Z` is not.
+    //
+    // - the omitted minutes and seconds are assumed to be zero.
+    //   the fraction can be only given to seconds.
+    //
+    // - the offset can be omitted for the local time,
+    //   but then `Parsed` cannot be converted to `DateTime`.
+    //
+    // - the expanded representations with more than 4 digits of years are not supported.
+
+    /// Consumes a separator between time components, if any.
+    fn separator(s: &str, extended: bool) -> Option<&str> {
+        match s.as_bytes().first() {
+            Some(&b':') if extended => Some(&s[1..]),
+            Some(&b'0'...b'9') if !extended => Some(s),
+            _ => None,
+        }
+    }
+
+    let year = try_consume!(scan::number(s, 4, 4));
+    let extended = s.starts_with('-');
+    if extended {
+        s = &s[1..];
+    }
+
+    if s.starts_with('W') || s.starts_with('w') {
+        s = &s[1..];
+        try!(parsed.set_isoyear(year));
+        try!(parsed.set_isoweek(try_consume!(scan::number(s, 2, 2))));
+        if extended {
+            s = try!(scan::char(s, b'-'));
+        }
+        let weekday = try_consume!(scan::number(s, 1, 1));
+        try!(parsed.set_weekday(try!(Weekday::from_i64(weekday - 1).ok_or(OUT_OF_RANGE))));
+    } else {
+        try!(parsed.set_year(year));
+        let ndigits = s.bytes().take_while(|&c| b'0' <= c && c <= b'9').count();
+        match (extended, ndigits) {
+            (_, 3) => try!(parsed.set_ordinal(try_consume!(scan::number(s, 3, 3)))),
+            (true, 2) => {
+                try!(parsed.set_month(try_consume!(scan::number(s, 2, 2))));
+                s = try!(scan::char(s, b'-'));
+                try!(parsed.set_day(try_consume!(scan::number(s, 2, 2))));
+            }
+            (false, 4) => {
+                try!(parsed.set_month(try_consume!(scan::number(s, 2, 2))));
+                try!(parsed.set_day(try_consume!(scan::number(s, 2, 2))));
+            }
+            _ if s.len() == ndigits => return Err(TOO_SHORT),
+            _ => return Err(INVALID),
+        }
+    }
+
+    s = match s.as_bytes().first() {
+        Some(&b't') | Some(&b'T') => &s[1..],
+        Some(_) => return Err(INVALID),
+        None => return Err(TOO_SHORT),
+    };
+
+    try!(parsed.set_hour(try_consume!(scan::number(s, 2, 2))));
+    let mut minute = 0;
+    let mut second = 0;
+    if let Some(s_) = separator(s, extended) {
+        s = s_;
+        minute = try_consume!(scan::number(s, 2, 2));
+        if let Some(s_) = separator(s, extended) {
+            s = s_;
+            second = try_consume!(scan::number(s, 2, 2));
+            if s.starts_with('.') || s.starts_with(',') {
+                try!(parsed.set_nanosecond(try_consume!(scan::nanosecond(&s[1..]))));
+            }
+        }
+    }
+    try!(parsed.set_minute(minute));
+    try!(parsed.set_second(second));
+
+    match s.as_bytes().first() {
+        Some(&b'z') | Some(&b'Z') => {
+            s = &s[1..];
+            try!(parsed.set_offset(0));
+        }
+        Some(&c) if c == b'+' || c == b'-' => {
+            s = &s[1..];
+            let hours = try_consume!(scan::number(s, 2, 2));
+            let mut minutes = 0;
+            if let Some(s_) = separator(s, extended) {
+                s = s_;
+                minutes = try_consume!(scan::number(s, 2, 2));
+            }
+            if hours >= 24 || minutes >= 60 { return Err(OUT_OF_RANGE); }
+            let offset = hours * 3600 + minutes * 60;
+            try!(parsed.set_offset(if c == b'-' { -offset } else { offset }));
+        }
+        _ => {} // the local time
+    }
+
+    Ok((s, ()))
+}
+
+/// Returns true if given literal separates the date and time in ISO 8601 (i.e. `T`).
+fn is_date_time_separator(prefix: &str) -> bool {
+    prefix == "T" || prefix == "t"
+}
+
+/// Consumes either `T` (in any case) or one or more whitespace between the date and time.
+fn lenient_date_time_separator(s: &str) -> ParseResult<&str> {
+    match s.as_bytes().first() {
+        Some(&b't') | Some(&b'T') => Ok(&s[1..]),
+        _ => scan::space(s),
+    }
+}
+
 /// Tries to parse given string into `parsed` with given formatting items.
 /// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
 /// There should be no trailing string after parsing;
@@ -202,6 +361,9 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
 ///   so one can prepend any number of whitespace then any number of zeroes before numbers.
 ///
 /// - (Still) obeying the intrinsic parsing width. This allows, for example, parsing `HHMMSS`.
+///
+/// - Strict about separators and offsets, unless `parsed` has been made with
+///   [`Parsed::lenient`](./struct.Parsed.html#method.lenient).
 pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<()>
         where I: Iterator<Item=Item<'a>> {
     macro_rules! try_consume {
@@ -210,6 +372,10 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
 
     for item in items {
         match item {
+            Item::Literal(prefix) if parsed.is_lenient() && is_date_time_separator(prefix) => {
+                s = try!(lenient_date_time_separator(s));
+            }
+
             Item::Literal(prefix) => {
                 if s.len() < prefix.len() { return Err(TOO_SHORT); }
                 if !s.starts_with(prefix) { return Err(INVALID); }
@@ -223,7 +389,19 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
             }
 
             Item::Space(_) | Item::OwnedSpace(_) => {
-                s = s.trim_left();
+                let trimmed = s.trim_left();
+                s = if parsed.is_lenient() && trimmed.len() == s.len() {
+                    // a space may be replaced with `T` only when followed by a number
+                    match lenient_date_time_separator(s) {
+                        Ok(s_) => match s_.as_bytes().first() {
+                            Some(&b'0'...b'9') => s_,
+                            _ => s,
+                        },
+                        Err(_) => s,
+                    }
+                } else {
+                    trimmed
+                };
             }
 
             Item::Numeric(spec, _pad) => {
@@ -252,8 +430,10 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
                     Nanosecond     => (9, false, Parsed::set_nanosecond),
                     Timestamp      => (usize::MAX, false, Parsed::set_timestamp),
 
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
+                    Internal(InternalNumeric { val: InternalNumericKind::Quarter }) =>
+                                      (1, false, Parsed::set_quarter),
+                    Internal(InternalNumeric { val: InternalNumericKind::WeekOfMonth }) =>
+                                      (1, false, Parsed::set_week_of_month),
                 };
 
                 s = s.trim_left();
@@ -309,7 +489,7 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
                     }
 
                     Nanosecond | Nanosecond3 | Nanosecond6 | Nanosecond9=> {
-                        if s.starts_with('.') {
+                        if s.starts_with('.') || (parsed.is_lenient() && s.starts_with(',')) {
                             let nano = try_consume!(scan::nanosecond(&s[1..]));
                             try!(parsed.set_nanosecond(nano));
                         }
@@ -317,12 +497,13 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
 
                     TimezoneName => return Err(BAD_FORMAT),
 
-                    TimezoneOffsetColon | TimezoneOffset => {
+                    TimezoneOffsetColon | TimezoneOffset if !parsed.is_lenient() => {
                         let offset = try_consume!(scan::timezone_offset(s.trim_left(),
                                                                         scan::colon_or_space));
                         try!(parsed.set_offset(offset as i64));
                     }
 
+                    TimezoneOffsetColon | TimezoneOffset |
                     TimezoneOffsetColonZ | TimezoneOffsetZ => {
                         let offset = try_consume!(scan::timezone_offset_zulu(s.trim_left(),
                                                                              scan::colon_or_space));
@@ -330,10 +511,12 @@ pub fn parse<'a, I>(parsed: &mut Parsed, mut s: &str, items: I) -> ParseResult<(
                     }
 
                     RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
-                    RFC3339 => try_consume!(parse_rfc3339(parsed, s)),
+                    RFC3339 => try_consume!(parse_rfc3339(parsed, s, false)),
 
-                    // for the future expansion
-                    Internal(ref int) => match int._dummy {},
+                    Internal(InternalFixed { val: InternalFixedKind::RFC3339Strict }) =>
+                        try_consume!(parse_rfc3339(parsed, s, true)),
+                    Internal(InternalFixed { val: InternalFixedKind::ISO8601 }) =>
+                        try_consume!(parse_iso8601(parsed, s)),
                 }
             }
 
@@ -622,7 +805,16 @@ fn test_rfc2822() {
         ("Tue, 20 Jan 2015 17:35:90 -0800", Err(OUT_OF_RANGE)), // bad second
         ("Tue, 20 Jan 2015 17:35:20 -0890", Err(OUT_OF_RANGE)), // bad offset
         ("6 Jun 1944 04:00:00Z", Err(INVALID)),                 // bad offset (zulu not allowed)
-        ("Tue, 20 Jan 2015 17:35:20 HAS", Err(NOT_ENOUGH))      // bad named time zone
+        ("Tue, 20 Jan 2015 17:35:20 HAS", Err(NOT_ENOUGH)),     // bad named time zone
+        ("Tue, 20 Jan 2015 17:35:20 J", Err(NOT_ENOUGH)),       // military time zone
+        ("Tue, 20 Jan 2015 17:35:20 -0000", Err(NOT_ENOUGH)),   // unknown time zone
+        ("Tue, 20 Jan 2015 17:35:20 pdt", Ok("Tue, 20 Jan 2015 17:35:20 -0700")),
+        ("Tue, 20 Jan 2015 17:35:20 -0800 (PST)", Ok("Tue, 20 Jan 2015 17:35:20 -0800")),
+        (" (c) Tue (d) , 20 (e\\) (f)) Jan(g)2015 17 (h) : 35 : (i) 20 -0800 (j (k)) ",
+         Ok("Tue, 20 Jan 2015 17:35:20 -0800")),                // comments everywhere
+        ("Tue, 20 Jan 2015\r\n 17:35:20\r\n\t-0800", Ok("Tue, 20 Jan 2015 17:35:20 -0800")), // FWS
+        ("Tue, 20 Jan 2015 17:35:20 -0800 (PST", Err(TOO_SHORT)), // unclosed comment
+        ("Tue, 20 Jan 2015 17:35:20 -0800 PST", Err(TOO_LONG)), // trailing garbage
     ];
 
     fn rfc2822_to_datetime(date: &str) -> ParseResult<DateTime<FixedOffset>> {
@@ -730,3 +922,62 @@ fn test_rfc3339() {
     };
 }
 
+#[cfg(test)]
+#[test]
+fn test_rfc3339_strict_and_iso8601() {
+    use DateTime;
+    use offset::FixedOffset;
+    use super::*;
+    use super::{RFC3339_STRICT, ISO8601, NOT_ENOUGH};
+
+    fn to_datetime(date: &str, item: Fixed) -> ParseResult<String> {
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, date, [Item::Fixed(item)].iter().cloned()));
+        let dt: DateTime<FixedOffset> = try!(parsed.to_datetime());
+        Ok(dt.to_rfc3339())
+    }
+
+    // the strict RFC 3339 parser accepts only what round-trips
+    let testdates = [
+        ("2015-01-20T17:35:20-08:00", Ok("2015-01-20T17:35:20-08:00")),
+        ("2015-01-20t17:35:20.000000004z", Ok("2015-01-20T17:35:20.000000004+00:00")),
+        ("2015-01-20T17:35:20.000000000452-08:00", Err(OUT_OF_RANGE)), // too many digits
+        ("2015-01-20T17:35:20-00:00", Err(INVALID)),                  // unknown offset
+        ("2015-01-20 17:35:20-08:00", Err(INVALID)),
+        ("20150120T173520Z", Err(INVALID)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        assert_eq!(to_datetime(date, RFC3339_STRICT), checkdate.map(|s| s.to_string()),
+                   "{:?} with the strict RFC 3339", date);
+    }
+
+    // the ISO 8601 parser accepts what RFC 3339 rejects
+    let testdates = [
+        ("2023-03-14T10:15:30Z", Ok("2023-03-14T10:15:30+00:00")),
+        ("20230314T101530Z", Ok("2023-03-14T10:15:30+00:00")),       // basic format
+        ("20230314T101530,5+0530", Ok("2023-03-14T10:15:30.500+05:30")),
+        ("2023-W11-2T10:15:30-08:00", Ok("2023-03-14T10:15:30-08:00")), // week date
+        ("2023W112T101530-08", Ok("2023-03-14T10:15:30-08:00")),
+        ("2020-W53-7T00:00Z", Ok("2021-01-03T00:00:00+00:00")),      // ISO year differs
+        ("2023-073T10:15Z", Ok("2023-03-14T10:15:00+00:00")),        // ordinal date
+        ("2023073T10Z", Ok("2023-03-14T10:00:00+00:00")),
+        ("2023-03-14t10:15:30.123456789z", Ok("2023-03-14T10:15:30.123456789+00:00")),
+        ("2023-03-14T10:15:30", Err(NOT_ENOUGH)),                    // local time
+        ("2023-03-14T101530Z", Err(TOO_LONG)),                       // mixed formats
+        ("20230314T10:15:30Z", Err(TOO_LONG)),
+        ("2023-03-14T10:15:30+0530", Err(TOO_LONG)),
+        ("2023-W11-8T10:15:30Z", Err(OUT_OF_RANGE)),
+        ("2023-366T10:15:30Z", Err(OUT_OF_RANGE)),
+        ("2023-03-14T10:15:30+24:00", Err(OUT_OF_RANGE)),
+        ("2023-03-14 10:15:30Z", Err(INVALID)),
+        ("2023-3-14T10:15:30Z", Err(INVALID)),
+        ("2023-03-14", Err(TOO_SHORT)),
+        ("20230314X10Z", Err(INVALID)),
+        ("202303", Err(TOO_SHORT)),
+        ("2023", Err(TOO_SHORT)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        assert_eq!(to_datetime(date, ISO8601), checkdate.map(|s| s.to_string()),
+                   "{:?} with ISO 8601", date);
+    }
+}
@@ -9,7 +9,7 @@ use oldtime::Duration as OldDuration;
 
 use {Datelike, Timelike};
 use Weekday;
-use div::div_rem;
+use div::{div_rem, mod_floor};
 use offset::{TimeZone, Offset, LocalResult, FixedOffset};
 use naive::{NaiveDate, NaiveTime, NaiveDateTime};
 use DateTime;
@@ -36,6 +36,8 @@ pub struct Parsed {
     /// Due to the common usage, if this field is missing but
     /// [`year_mod_100`](#structfield.year_mod_100) is present,
     /// it is inferred to 19 when `year_mod_100 >= 70` and 20 otherwise.
+    /// This can be changed with
+    /// [`with_two_digit_year_window`](#method.with_two_digit_year_window).
     pub year_div_100: Option<i32>,
 
     /// Year modulo 100. Implies that the year is >= 1 BCE when set.
@@ -53,6 +55,8 @@ pub struct Parsed {
     /// Due to the common usage, if this field is missing but
     /// [`isoyear_mod_100`](#structfield.isoyear_mod_100) is present,
     /// it is inferred to 19 when `isoyear_mod_100 >= 70` and 20 otherwise.
+    /// This can be changed with
+    /// [`with_two_digit_year_window`](#method.with_two_digit_year_window).
     pub isoyear_div_100: Option<i32>,
 
     /// Year in the [ISO week date](../naive/struct.NaiveDate.html#week-date), modulo 100.
@@ -62,6 +66,9 @@ pub struct Parsed {
     /// Month (1--12).
     pub month: Option<u32>,
 
+    /// Quarter of the year (1--4).
+    pub quarter: Option<u32>,
+
     /// Week number, where the week 1 starts at the first Sunday of January
     /// (0--53, 1--53 or 1--52 depending on the year).
     pub week_from_sun: Option<u32>,
@@ -74,6 +81,10 @@ pub struct Parsed {
     /// (1--52 or 1--53 depending on the year).
     pub isoweek: Option<u32>,
 
+    /// Week of the month, where the week 1 contains the first day of the month
+    /// and weeks start on Monday (1--6 depending on the month).
+    pub week_of_month: Option<u32>,
+
     /// Day of the week.
     pub weekday: Option<Weekday>,
 
@@ -106,6 +117,13 @@ pub struct Parsed {
     /// Offset from the local time to UTC, in seconds.
     pub offset: Option<i32>,
 
+    /// The first year of the 100-year window which two-digit years fall in.
+    year_window: i32,
+
+    /// Whether the parser should accept common deviations from the format.
+    /// See [`Parsed::lenient`](#method.lenient).
+    lenient: bool,
+
     /// A dummy field to make this type not fully destructible (required for API stability).
     _dummy: (),
 }
@@ -125,11 +143,12 @@ impl Default for Parsed {
     fn default() -> Parsed {
         Parsed {
             year: None, year_div_100: None, year_mod_100: None, isoyear: None,
-            isoyear_div_100: None, isoyear_mod_100: None, month: None,
-            week_from_sun: None, week_from_mon: None, isoweek: None, weekday: None,
+            isoyear_div_100: None, isoyear_mod_100: None, month: None, quarter: None,
+            week_from_sun: None, week_from_mon: None, isoweek: None, week_of_month: None,
+            weekday: None,
             ordinal: None, day: None, hour_div_12: None, hour_mod_12: None, minute: None,
             second: None, nanosecond: None, timestamp: None, offset: None,
-            _dummy: (),
+            year_window: 1970, lenient: false, _dummy: (),
         }
     }
 }
@@ -140,6 +159,53 @@ impl Parsed {
         Parsed::default()
     }
 
+    /// Returns the initial value of parsed parts, which makes the parser lenient.
+    ///
+    /// In addition to what the parser normally accepts (e.g. numbers without leading zeroes),
+    /// the lenient parser also accepts the following deviations from the format:
+    ///
+    /// - A comma (`,`) in place of a dot (`.`) before fractional seconds.
+    /// - A space in place of a literal `T` between the date and time, and vice versa.
+    ///   A literal `T` is also matched case-insensitively.
+    /// - `Z` or `z` in place of a numeric offset (`%z` or `%:z`), as an offset of `+00:00`.
+    pub fn lenient() -> Parsed {
+        Parsed { lenient: true, ..Parsed::default() }
+    }
+
+    /// Returns true if the parser is [lenient](#method.lenient).
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Changes how the full year is inferred from a two-digit year
+    /// when the century number is missing.
+    ///
+    /// Two-digit years are mapped to the 100-year window starting at `first_year`.
+    /// The default is 1970, so `70` to `99` are in the 1900s and `00` to `69` are in the 2000s.
+    /// Years outside of the window can still be parsed with the full year or the century number.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::format::{parse, Parsed, StrftimeItems};
+    ///
+    /// let mut parsed = Parsed::new().with_two_digit_year_window(1950);
+    /// parse(&mut parsed, "07/08/51", StrftimeItems::new("%m/%d/%y")).unwrap();
+    /// assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_ymd(1951, 7, 8)));
+    ///
+    /// let mut parsed = Parsed::new().with_two_digit_year_window(1950);
+    /// parse(&mut parsed, "07/08/49", StrftimeItems::new("%m/%d/%y")).unwrap();
+    /// assert_eq!(parsed.to_naive_date(), Ok(NaiveDate::from_ymd(2049, 7, 8)));
+    /// ~~~~
+    pub fn with_two_digit_year_window(self, first_year: i32) -> Parsed {
+        Parsed { year_window: first_year, ..self }
+    }
+
+    /// Returns the first year of the 100-year window which two-digit years fall in.
+    /// See [`with_two_digit_year_window`](#method.with_two_digit_year_window).
+    pub fn two_digit_year_window(&self) -> i32 {
+        self.year_window
+    }
+
     /// Tries to set the [`year`](#structfield.year) field from given value.
     pub fn set_year(&mut self, value: i64) -> ParseResult<()> {
         set_if_consistent(&mut self.year, try!(value.to_i32().ok_or(OUT_OF_RANGE)))
@@ -179,6 +245,11 @@ impl Parsed {
         set_if_consistent(&mut self.month, try!(value.to_u32().ok_or(OUT_OF_RANGE)))
     }
 
+    /// Tries to set the [`quarter`](#structfield.quarter) field from given value.
+    pub fn set_quarter(&mut self, value: i64) -> ParseResult<()> {
+        set_if_consistent(&mut self.quarter, try!(value.to_u32().ok_or(OUT_OF_RANGE)))
+    }
+
     /// Tries to set the [`week_from_sun`](#structfield.week_from_sun) field from given value.
     pub fn set_week_from_sun(&mut self, value: i64) -> ParseResult<()> {
         set_if_consistent(&mut self.week_from_sun, try!(value.to_u32().ok_or(OUT_OF_RANGE)))
@@ -194,6 +265,11 @@ impl Parsed {
         set_if_consistent(&mut self.isoweek, try!(value.to_u32().ok_or(OUT_OF_RANGE)))
     }
 
+    /// Tries to set the [`week_of_month`](#structfield.week_of_month) field from given value.
+    pub fn set_week_of_month(&mut self, value: i64) -> ParseResult<()> {
+        set_if_consistent(&mut self.week_of_month, try!(value.to_u32().ok_or(OUT_OF_RANGE)))
+    }
+
     /// Tries to set the [`weekday`](#structfield.weekday) field from given value.
     pub fn set_weekday(&mut self, value: Weekday) -> ParseResult<()> {
         set_if_consistent(&mut self.weekday, value)
@@ -263,13 +339,14 @@ impl Parsed {
     /// - Year, month, day.
     /// - Year, day of the year (ordinal).
     /// - Year, week number counted from Sunday or Monday, day of the week.
+    /// - Year, month, week of the month, day of the week.
     /// - ISO week date.
     ///
     /// Gregorian year and ISO week date year can have their century number (`*_div_100`) omitted,
     /// the two-digit year is used to guess the century number then.
     pub fn to_naive_date(&self) -> ParseResult<NaiveDate> {
-        fn resolve_year(y: Option<i32>, q: Option<i32>,
-                        r: Option<i32>) -> ParseResult<Option<i32>> {
+        fn resolve_year(y: Option<i32>, q: Option<i32>, r: Option<i32>,
+                        window: i32) -> ParseResult<Option<i32>> {
             match (y, q, r) {
                 // if there is no further information, simply return the given full year.
                 // this is a common case, so let's avoid division here.
@@ -297,9 +374,14 @@ impl Parsed {
                     Ok(Some(try!(y.ok_or(OUT_OF_RANGE))))
                 },
 
-                // we only have modulo. try to interpret a modulo as a conventional two-digit year.
+                // we only have modulo. try to interpret a modulo as a two-digit year
+                // in the 100-year window, which is 1970--2069 by default.
                 // note: we are affected by Rust issue #18060. avoid multiple range patterns.
-                (None, None, Some(r @ 0...99)) => Ok(Some(r + if r < 70 {2000} else {1900})),
+                (None, None, Some(r @ 0...99)) => {
+                    let y = window as i64 + mod_floor(r as i64 - window as i64, 100);
+                    if y < 0 { return Err(OUT_OF_RANGE); }
+                    Ok(Some(try!(y.to_i32().ok_or(OUT_OF_RANGE))))
+                },
 
                 // otherwise it is an out-of-bound or insufficient condition.
                 (None, Some(_), None) => Err(NOT_ENOUGH),
@@ -308,9 +390,10 @@ impl Parsed {
         }
 
         let given_year =
-            try!(resolve_year(self.year, self.year_div_100, self.year_mod_100));
+            try!(resolve_year(self.year, self.year_div_100, self.year_mod_100, self.year_window));
         let given_isoyear =
-            try!(resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100));
+            try!(resolve_year(self.isoyear, self.isoyear_div_100, self.isoyear_mod_100,
+                              self.year_window));
 
         // verify the normal year-month-day date.
         let verify_ymd = |date: NaiveDate| {
@@ -349,15 +432,19 @@ impl Parsed {
              self.weekday.unwrap_or(weekday) == weekday)
         };
 
-        // verify the ordinal and other (non-ISO) week dates.
+        // verify the ordinal, the quarter and other (non-ISO) week dates.
         let verify_ordinal = |date: NaiveDate| {
             let ordinal = date.ordinal();
             let weekday = date.weekday();
             let week_from_sun = (ordinal as i32 - weekday.num_days_from_sunday() as i32 + 7) / 7;
             let week_from_mon = (ordinal as i32 - weekday.num_days_from_monday() as i32 + 7) / 7;
+            let quarter = date.quarter();
+            let week_of_month = date.week_of_month();
             (self.ordinal.unwrap_or(ordinal) == ordinal &&
              self.week_from_sun.map_or(week_from_sun, |v| v as i32) == week_from_sun &&
-             self.week_from_mon.map_or(week_from_mon, |v| v as i32) == week_from_mon)
+             self.week_from_mon.map_or(week_from_mon, |v| v as i32) == week_from_mon &&
+             self.quarter.unwrap_or(quarter) == quarter &&
+             self.week_of_month.unwrap_or(week_of_month) == week_of_month)
         };
 
         // test several possibilities.
@@ -426,6 +513,21 @@ impl Parsed {
                 (verify_ymd(date) && verify_isoweekdate(date) && verify_ordinal(date), date)
             },
 
+            (Some(year), _, &Parsed { month: Some(month), week_of_month: Some(week_of_month),
+                                      weekday: Some(weekday), .. }) => {
+                // year, month, week of the month (starting at the first day), day of the week
+                let first = try!(NaiveDate::from_ymd_opt(year, month, 1).ok_or(OUT_OF_RANGE));
+                if week_of_month == 0 || week_of_month > 6 { return Err(OUT_OF_RANGE); }
+                let ndays = (week_of_month as i32 - 1) * 7 +
+                            weekday.num_days_from_monday() as i32 -
+                            first.weekday().num_days_from_monday() as i32;
+                let date = try!(first.checked_add_signed(OldDuration::days(ndays as i64))
+                                     .ok_or(OUT_OF_RANGE));
+                if date.month() != month { return Err(OUT_OF_RANGE); } // early exit
+
+                (verify_ymd(date) && verify_isoweekdate(date) && verify_ordinal(date), date)
+            },
+
             (_, Some(isoyear), &Parsed { isoweek: Some(isoweek), weekday: Some(weekday), .. }) => {
                 // ISO year, week, day of the week
                 let date = NaiveDate::from_isoywd_opt(isoyear, isoweek, weekday);
@@ -636,6 +738,7 @@ impl Parsed {
 
 #[cfg(test)]
 mod tests {
+    use std::i32;
     use super::Parsed;
     use super::super::{OUT_OF_RANGE, IMPOSSIBLE, NOT_ENOUGH};
     use Datelike;
@@ -739,6 +842,24 @@ mod tests {
         assert_eq!(parse!(year_mod_100: 70, month: 1, day: 2), ymd(1970, 1, 2));
         assert_eq!(parse!(year_mod_100: 69, month: 1, day: 2), ymd(2069, 1, 2));
 
+        // ymd: two-digit years in other windows
+        let windowed = |window, r| Parsed { year_mod_100: Some(r), month: Some(1), day: Some(2),
+                                            ..Parsed::new().with_two_digit_year_window(window) }
+                                  .to_naive_date();
+        assert_eq!(windowed(1950, 50), ymd(1950, 1, 2));
+        assert_eq!(windowed(1950, 49), ymd(2049, 1, 2));
+        assert_eq!(windowed(2000, 99), ymd(2099, 1, 2));
+        assert_eq!(windowed(2000, 0), ymd(2000, 1, 2));
+        assert_eq!(windowed(1900, 69), ymd(1969, 1, 2));
+        assert_eq!(windowed(1, 0), ymd(100, 1, 2));
+        assert_eq!(windowed(-50, 49), ymd(49, 1, 2));
+        assert_eq!(windowed(-50, 50), Err(OUT_OF_RANGE));
+        assert_eq!(windowed(i32::MAX, 0), Err(OUT_OF_RANGE));
+        assert_eq!(Parsed { year_div_100: Some(19), year_mod_100: Some(10), month: Some(1),
+                            day: Some(2), ..Parsed::new().with_two_digit_year_window(2000) }
+                       .to_naive_date(),
+                   ymd(1910, 1, 2)); // the century number takes precedence
+
         // ymd: out-of-range conditions
         assert_eq!(parse!(year_div_100: 19, year_mod_100: 84, month: 2, day: 29),
                    ymd(1984, 2, 29));
@@ -829,6 +950,30 @@ mod tests {
         assert_eq!(parse!(isoyear: 2005, isoweek: 5, weekday: Thu), ymd(2005, 2, 3));
         assert_eq!(parse!(isoyear: 2005, weekday: Thu), Err(NOT_ENOUGH));
 
+        // weeks of the month
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 2), Err(NOT_ENOUGH));
+        assert_eq!(parse!(year: 2018, week_of_month: 2, weekday: Mon), Err(NOT_ENOUGH));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 1, weekday: Wed), ymd(2018, 8, 1));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 1, weekday: Mon),
+                   Err(OUT_OF_RANGE));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 2, weekday: Mon), ymd(2018, 8, 6));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 5, weekday: Fri), ymd(2018, 8, 31));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 5, weekday: Sat),
+                   Err(OUT_OF_RANGE));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 0, weekday: Mon),
+                   Err(OUT_OF_RANGE));
+        assert_eq!(parse!(year: 2018, month: 8, week_of_month: 7, weekday: Mon),
+                   Err(OUT_OF_RANGE));
+        assert_eq!(parse!(year: 2018, month: 8, day: 6, week_of_month: 2), ymd(2018, 8, 6));
+        assert_eq!(parse!(year: 2018, month: 8, day: 6, week_of_month: 1), Err(IMPOSSIBLE));
+
+        // quarters
+        assert_eq!(parse!(year: 2018, quarter: 3), Err(NOT_ENOUGH));
+        assert_eq!(parse!(year: 2018, month: 8, day: 6, quarter: 3), ymd(2018, 8, 6));
+        assert_eq!(parse!(year: 2018, month: 8, day: 6, quarter: 2), Err(IMPOSSIBLE));
+        assert_eq!(parse!(year: 2018, ordinal: 1, quarter: 1), ymd(2018, 1, 1));
+        assert_eq!(parse!(year: 2018, ordinal: 1, quarter: 4), Err(IMPOSSIBLE));
+
         // year and ordinal
         assert_eq!(parse!(ordinal: 123), Err(NOT_ENOUGH));
         assert_eq!(parse!(year: 2000, ordinal: 0), Err(OUT_OF_RANGE));
@@ -167,6 +167,37 @@ pub fn colon_or_space(s: &str) -> ParseResult<&str> {
     Ok(s.trim_left_matches(|c: char| c == ':' || c.is_whitespace()))
 }
 
+/// Consumes any number (including zero) of spaces and RFC 2822 comments,
+/// i.e. the `CFWS` production in RFC 2822 Section 3.2.3.
+///
+/// Comments are parenthesized, can be nested and can contain a backslash-escaped character.
+/// The folding white space is a sequence of white space characters and thus consumed.
+pub fn comment_or_space(s: &str) -> ParseResult<&str> {
+    let mut s = s.trim_left();
+    while s.starts_with('(') {
+        let mut depth = 0usize;
+        let mut escaped = false;
+        let mut end = None;
+        for (i, c) in s.bytes().enumerate() {
+            match c {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 { end = Some(i); break; }
+                }
+                _ => {}
+            }
+        }
+        match end {
+            Some(i) => s = s[i + 1..].trim_left(),
+            None => return Err(TOO_SHORT), // unclosed comment
+        }
+    }
+    Ok(s)
+}
+
 /// Tries to parse `[-+]\d\d` continued by `\d\d`. Return an offset in seconds if possible.
 ///
 /// The additional `colon` may be used to parse a mandatory or optional `:`
@@ -37,6 +37,10 @@ Spec. | Example       | Description
       |               |
 `%j`  | `189`         | Day of the year (001--366), zero-padded to 3 digits.
       |               |
+`%q`  | `3`           | Quarter of the year (1--4).
+`%Q`  | `Q3`          | Quarter of the year with a `Q` prefix. Same to `Q%q`.
+`%i`  | `2`           | Week of the month (1--6), where weeks start on Monday. [9]
+      |               |
 `%D`  | `07/08/01`    | Month-day-year format. Same to `%m/%d/%y`.
 `%x`  | `07/08/01`    | Same to `%D`.
 `%F`  | `2001-07-08`  | Year-month-day format (ISO 8601). Same to `%Y-%m-%d`.
@@ -144,9 +148,15 @@ Notes:
    Note that they can read nothing if the fractional part is zero or
    the next character is not `.` however will print with the specified length.
 
+9. `%i`:
+   Week 1 is the week containing the first day of the month, so it can have less than 7 days.
+   The week of the month is given by
+   [`Datelike::week_of_month`](../trait.Datelike.html#method.week_of_month)
+   and can be combined with the year, the month and the weekday (e.g. `%Y-%m W%i %u`) in parsing.
+
 */
 
-use super::{Item, Numeric, Fixed, Pad};
+use super::{Item, Numeric, Fixed, Pad, InternalNumeric, InternalNumericKind};
 
 /// Parsing iterator for `strftime`-like format strings.
 #[derive(Clone, Debug)]
@@ -227,6 +237,7 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'I' => num0!(Hour12),
                     'M' => num0!(Minute),
                     'P' => fix!(LowerAmPm),
+                    'Q' => recons![lit!("Q"), internal_num!(Quarter)],
                     'R' => recons![num0!(Hour), lit!(":"), num0!(Minute)],
                     'S' => num0!(Second),
                     'T' => recons![num0!(Hour), lit!(":"), num0!(Minute), lit!(":"), num0!(Second)],
@@ -245,12 +256,14 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'e' => nums!(Day),
                     'f' => num0!(Nanosecond),
                     'g' => num0!(IsoYearMod100),
+                    'i' => internal_num!(WeekOfMonth),
                     'j' => num0!(Ordinal),
                     'k' => nums!(Hour),
                     'l' => nums!(Hour12),
                     'm' => num0!(Month),
                     'n' => sp!("\n"),
                     'p' => fix!(UpperAmPm),
+                    'q' => internal_num!(Quarter),
                     'r' => recons![num0!(Hour12), lit!(":"), num0!(Minute), lit!(":"),
                                    num0!(Second), sp!(" "), fix!(UpperAmPm)],
                     's' => num!(Timestamp),
@@ -368,6 +381,10 @@ fn test_strftime_items() {
     assert_eq!(parse_and_collect("%-e"), [num!(Day)]);
     assert_eq!(parse_and_collect("%0e"), [num0!(Day)]);
     assert_eq!(parse_and_collect("%_e"), [nums!(Day)]);
+    assert_eq!(parse_and_collect("%q"), [internal_num!(Quarter)]);
+    assert_eq!(parse_and_collect("%Q"), [lit!("Q"), internal_num!(Quarter)]);
+    assert_eq!(parse_and_collect("%-Q"), [Item::Error]);
+    assert_eq!(parse_and_collect("%i"), [internal_num!(WeekOfMonth)]);
 }
 
 #[cfg(test)]
@@ -398,6 +415,9 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%g").to_string(), "01");
     assert_eq!(dt.format("%V").to_string(), "27");
     assert_eq!(dt.format("%j").to_string(), "189");
+    assert_eq!(dt.format("%q").to_string(), "3");
+    assert_eq!(dt.format("%Q").to_string(), "Q3");
+    assert_eq!(dt.format("%i").to_string(), "2");
     assert_eq!(dt.format("%D").to_string(), "07/08/01");
     assert_eq!(dt.format("%x").to_string(), "07/08/01");
     assert_eq!(dt.format("%F").to_string(), "2001-07-08");
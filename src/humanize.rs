@@ -0,0 +1,407 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Human-readable relative descriptions of a duration, like "in 2 days" or "3 hours ago".
+//!
+//! The text is produced by a [`Language`](./trait.Language.html) implementation,
+//! so that other languages can be plugged in. [`English`](./struct.English.html) is the default.
+//!
+//! ~~~~
+//! use chrono::Duration;
+//! use chrono::humanize::HumanDuration;
+//!
+//! let d = Duration::days(3) + Duration::hours(4) + Duration::minutes(5);
+//! assert_eq!(HumanDuration::new(d).to_string(), "in 3 days");
+//! assert_eq!(HumanDuration::new(-d).precision(2).to_string(), "3 days, 4 hours ago");
+//! assert_eq!(HumanDuration::new(Duration::milliseconds(300)).to_string(), "now");
+//! ~~~~
+//!
+//! Conversely, [`parse_duration`](./fn.parse_duration.html) reads a duration
+//! written by humans, like `2h 30m` or `3 weeks`.
+
+use std::{fmt, cmp};
+use std::error::Error;
+use oldtime::Duration as OldDuration;
+
+/// The unit of time used in a human-readable duration.
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+pub enum Unit {
+    /// Seven days.
+    Week,
+    /// 86,400 seconds.
+    Day,
+    /// 3,600 seconds.
+    Hour,
+    /// 60 seconds.
+    Minute,
+    /// A second.
+    Second,
+}
+
+impl Unit {
+    /// Returns the number of seconds in the unit.
+    fn num_seconds(&self) -> u64 {
+        match *self {
+            Unit::Week => 604_800,
+            Unit::Day => 86_400,
+            Unit::Hour => 3_600,
+            Unit::Minute => 60,
+            Unit::Second => 1,
+        }
+    }
+}
+
+const UNITS: [Unit; 5] = [Unit::Week, Unit::Day, Unit::Hour, Unit::Minute, Unit::Second];
+
+/// The language used to describe a duration.
+///
+/// Only `count_unit` has to be implemented, other methods default to English.
+pub trait Language {
+    /// Writes a positive amount of given unit, e.g. "3 days".
+    fn count_unit(&self, f: &mut fmt::Formatter, count: u64, unit: Unit) -> fmt::Result;
+
+    /// Writes a separator between amounts of different units.
+    fn separator(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(", ")
+    }
+
+    /// Writes a duration which is too short to be shown in any unit.
+    fn now(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("now")
+    }
+
+    /// Writes a duration into the future, given its absolute value.
+    fn future(&self, f: &mut fmt::Formatter, span: fmt::Arguments) -> fmt::Result {
+        write!(f, "in {}", span)
+    }
+
+    /// Writes a duration into the past, given its absolute value.
+    fn past(&self, f: &mut fmt::Formatter, span: fmt::Arguments) -> fmt::Result {
+        write!(f, "{} ago", span)
+    }
+}
+
+/// The English language.
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash, Default)]
+pub struct English;
+
+impl Language for English {
+    fn count_unit(&self, f: &mut fmt::Formatter, count: u64, unit: Unit) -> fmt::Result {
+        let name = match unit {
+            Unit::Week => "week",
+            Unit::Day => "day",
+            Unit::Hour => "hour",
+            Unit::Minute => "minute",
+            Unit::Second => "second",
+        };
+        write!(f, "{} {}{}", count, name, if count == 1 { "" } else { "s" })
+    }
+}
+
+/// A `Duration` which is displayed in the human-readable form.
+///
+/// Only the largest `precision` units (1 by default) are shown
+/// and the remainder is truncated towards zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HumanDuration<L = English> {
+    duration: OldDuration,
+    precision: usize,
+    language: L,
+}
+
+impl HumanDuration<English> {
+    /// Makes a new `HumanDuration` in English with the precision of one unit.
+    pub fn new(duration: OldDuration) -> HumanDuration<English> {
+        HumanDuration { duration: duration, precision: 1, language: English }
+    }
+}
+
+impl<L: Language> HumanDuration<L> {
+    /// Sets the maximum number of units shown. `0` is treated as `1`.
+    pub fn precision(self, precision: usize) -> HumanDuration<L> {
+        HumanDuration { precision: precision, ..self }
+    }
+
+    /// Changes the language.
+    pub fn with_language<L2: Language>(self, language: L2) -> HumanDuration<L2> {
+        HumanDuration { duration: self.duration, precision: self.precision, language: language }
+    }
+
+    /// Returns the underlying duration.
+    pub fn duration(&self) -> OldDuration {
+        self.duration
+    }
+}
+
+impl From<OldDuration> for HumanDuration<English> {
+    fn from(duration: OldDuration) -> HumanDuration<English> {
+        HumanDuration::new(duration)
+    }
+}
+
+/// The absolute value of a duration in the largest units.
+struct Span<'a, L: Language + 'a> {
+    secs: u64,
+    precision: usize,
+    language: &'a L,
+}
+
+impl<'a, L: Language + 'a> fmt::Display for Span<'a, L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut secs = self.secs;
+        let mut shown = 0;
+        for unit in UNITS.iter() {
+            if shown == cmp::max(self.precision, 1) { break; }
+            let count = secs / unit.num_seconds();
+            secs %= unit.num_seconds();
+            if count > 0 {
+                if shown > 0 { try!(self.language.separator(f)); }
+                try!(self.language.count_unit(f, count, *unit));
+                shown += 1;
+            } else if shown > 0 {
+                // an intermediate zero unit still counts towards the precision,
+                // so "1 week, 3 seconds" is never shown at the precision of 2
+                shown += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<L: Language> fmt::Display for HumanDuration<L> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // `Duration::min_value()` has no positive counterpart, so we take the seconds first
+        // (`wrapping_abs` of `i64::MIN` still gives the correct `u64` below)
+        let secs = self.duration.num_seconds();
+        if secs == 0 {
+            return self.language.now(f);
+        }
+        let span = Span { secs: secs.wrapping_abs() as u64, precision: self.precision,
+                          language: &self.language };
+        if secs > 0 {
+            self.language.future(f, format_args!("{}", span))
+        } else {
+            self.language.past(f, format_args!("{}", span))
+        }
+    }
+}
+
+/// An error from [`parse_duration`](./fn.parse_duration.html).
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum ParseDurationError {
+    /// The input is empty or not a sequence of numbers followed by units.
+    Invalid,
+
+    /// The unit is not known.
+    UnknownUnit,
+
+    /// The unit is months or years, which have no fixed length.
+    /// Use [`Months`](../struct.Months.html) for them instead.
+    CalendarUnit,
+
+    /// The duration does not fit in `Duration`.
+    OutOfRange,
+}
+
+impl ParseDurationError {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            ParseDurationError::Invalid => "invalid duration",
+            ParseDurationError::UnknownUnit => "unknown duration unit",
+            ParseDurationError::CalendarUnit => "months and years have no fixed duration",
+            ParseDurationError::OutOfRange => "duration out of range",
+        }
+    }
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Error for ParseDurationError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Returns the length of the unit as seconds and nanoseconds, only one of which is non-zero.
+///
+/// The unit is case-insensitive except for `M` (months) and `m` (minutes).
+fn unit_length(unit: &str) -> Result<(u64, u64), ParseDurationError> {
+    if unit == "M" {
+        return Err(ParseDurationError::CalendarUnit);
+    }
+    Ok(match &*unit.to_lowercase() {
+        "w" | "week" | "weeks" => (604_800, 0),
+        "d" | "day" | "days" => (86_400, 0),
+        "h" | "hr" | "hrs" | "hour" | "hours" => (3_600, 0),
+        "m" | "min" | "mins" | "minute" | "minutes" => (60, 0),
+        "s" | "sec" | "secs" | "second" | "seconds" => (1, 0),
+        "ms" | "msec" | "millisecond" | "milliseconds" => (0, 1_000_000),
+        "us" | "\u{b5}s" | "usec" | "microsecond" | "microseconds" => (0, 1_000),
+        "ns" | "nsec" | "nanosecond" | "nanoseconds" => (0, 1),
+        "mo" | "month" | "months" | "y" | "yr" | "yrs" | "year" | "years" =>
+            return Err(ParseDurationError::CalendarUnit),
+        _ => return Err(ParseDurationError::UnknownUnit),
+    })
+}
+
+/// Parses a human-friendly duration like `2h 30m`, `1 day` or `3 weeks`.
+///
+/// The input is a sequence of non-negative integers, each followed by a unit,
+/// optionally separated by whitespace. The units are weeks (`w`), days (`d`), hours (`h`),
+/// minutes (`m`), seconds (`s`), milliseconds (`ms`), microseconds (`us`) and
+/// nanoseconds (`ns`), which can be also spelled out like `min`, `hour` or `seconds`.
+/// The units are case-insensitive, except that `M` means months while `m` means minutes.
+/// A week is always seven days and a day is always 86,400 seconds.
+///
+/// Months (`M`, `mo`, `month`) and years (`y`, `yr`, `year`) are rejected with
+/// `ParseDurationError::CalendarUnit`, because their lengths vary
+/// while `Duration` always has a fixed length.
+/// Chrono has no duration type for calendar units;
+/// parse them separately and add them with [`Months`](../struct.Months.html) instead.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::Duration;
+/// use chrono::humanize::{parse_duration, ParseDurationError};
+///
+/// assert_eq!(parse_duration("2h 30m"), Ok(Duration::minutes(150)));
+/// assert_eq!(parse_duration("1 Day"), Ok(Duration::days(1)));
+/// assert_eq!(parse_duration("3 weeks"), Ok(Duration::weeks(3)));
+/// assert_eq!(parse_duration("7d"), Ok(Duration::days(7)));
+/// assert_eq!(parse_duration("1s 500ms"), Ok(Duration::milliseconds(1_500)));
+/// assert_eq!(parse_duration("2 months"), Err(ParseDurationError::CalendarUnit));
+/// ~~~~
+pub fn parse_duration(s: &str) -> Result<OldDuration, ParseDurationError> {
+    let max_secs = OldDuration::max_value().num_seconds() as u64;
+    let (mut secs, mut nanos) = (0u64, 0u64);
+    let mut s = s.trim_left();
+    if s.is_empty() {
+        return Err(ParseDurationError::Invalid);
+    }
+    while !s.is_empty() {
+        let digits = s.find(|c: char| !c.is_digit(10)).unwrap_or(s.len());
+        if digits == 0 {
+            return Err(ParseDurationError::Invalid);
+        }
+        // a number too large for `u64` is certainly out of range
+        let count: u64 = try!(s[..digits].parse().map_err(|_| ParseDurationError::OutOfRange));
+        s = s[digits..].trim_left();
+
+        let letters = s.find(|c: char| !c.is_alphabetic()).unwrap_or(s.len());
+        if letters == 0 {
+            return Err(ParseDurationError::Invalid);
+        }
+        let (unit_secs, unit_nanos) = try!(unit_length(&s[..letters]));
+        s = s[letters..].trim_left();
+
+        let (add_secs, add_nanos) = if unit_nanos == 0 {
+            (count.checked_mul(unit_secs), 0)
+        } else {
+            let per_sec = 1_000_000_000 / unit_nanos;
+            (Some(count / per_sec), (count % per_sec) * unit_nanos)
+        };
+        nanos += add_nanos;
+        secs = try!(add_secs.and_then(|add| secs.checked_add(add))
+                            .and_then(|secs| secs.checked_add(nanos / 1_000_000_000))
+                            .ok_or(ParseDurationError::OutOfRange));
+        nanos %= 1_000_000_000;
+    }
+    if secs > max_secs {
+        return Err(ParseDurationError::OutOfRange);
+    }
+    OldDuration::seconds(secs as i64).checked_add(&OldDuration::nanoseconds(nanos as i64))
+                                     .ok_or(ParseDurationError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use super::{HumanDuration, Language, Unit, parse_duration, ParseDurationError};
+    use oldtime::Duration;
+
+    #[test]
+    fn test_humanize() {
+        let human = |d: Duration, precision| HumanDuration::new(d).precision(precision).to_string();
+        assert_eq!(human(Duration::zero(), 1), "now");
+        assert_eq!(human(Duration::milliseconds(-999), 1), "now");
+        assert_eq!(human(Duration::seconds(1), 1), "in 1 second");
+        assert_eq!(human(Duration::seconds(-59), 1), "59 seconds ago");
+        assert_eq!(human(Duration::seconds(90), 1), "in 1 minute");
+        assert_eq!(human(Duration::seconds(90), 2), "in 1 minute, 30 seconds");
+        assert_eq!(human(Duration::days(14), 3), "in 2 weeks");
+        assert_eq!(human(Duration::days(-15) - Duration::minutes(3), 2), "2 weeks, 1 day ago");
+        assert_eq!(human(Duration::days(-15) - Duration::minutes(3), 3), "2 weeks, 1 day ago");
+        assert_eq!(human(Duration::days(-15) - Duration::minutes(3), 4),
+                   "2 weeks, 1 day, 3 minutes ago");
+        assert_eq!(human(Duration::weeks(1) + Duration::seconds(3), 2), "in 1 week");
+        assert_eq!(human(Duration::hours(5), 0), "in 5 hours");
+        assert_eq!(human(Duration::max_value(), 1), "in 15250284452 weeks");
+        assert_eq!(human(Duration::min_value(), 1), "15250284452 weeks ago");
+    }
+
+    #[test]
+    fn test_humanize_language() {
+        struct Terse;
+
+        impl Language for Terse {
+            fn count_unit(&self, f: &mut fmt::Formatter, count: u64, unit: Unit) -> fmt::Result {
+                let suffix = match unit {
+                    Unit::Week => "w", Unit::Day => "d", Unit::Hour => "h",
+                    Unit::Minute => "m", Unit::Second => "s",
+                };
+                write!(f, "{}{}", count, suffix)
+            }
+
+            fn separator(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(" ")
+            }
+
+            fn past(&self, f: &mut fmt::Formatter, span: fmt::Arguments) -> fmt::Result {
+                write!(f, "-{}", span)
+            }
+        }
+
+        let d = -(Duration::hours(2) + Duration::minutes(30));
+        assert_eq!(HumanDuration::new(d).precision(2).with_language(Terse).to_string(), "-2h 30m");
+        assert_eq!(HumanDuration::new(-d).with_language(Terse).to_string(), "in 2h");
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use self::ParseDurationError::*;
+
+        assert_eq!(parse_duration("2h 30m"), Ok(Duration::minutes(150)));
+        assert_eq!(parse_duration("2h30m"), Ok(Duration::minutes(150)));
+        assert_eq!(parse_duration(" 1 day "), Ok(Duration::days(1)));
+        assert_eq!(parse_duration("3 weeks 1 hour"), Ok(Duration::weeks(3) + Duration::hours(1)));
+        assert_eq!(parse_duration("7d"), Ok(Duration::days(7)));
+        assert_eq!(parse_duration("1s 500ms 20us 3ns"), Ok(Duration::nanoseconds(1_500_020_003)));
+        assert_eq!(parse_duration("1500ms"), Ok(Duration::milliseconds(1_500)));
+        assert_eq!(parse_duration("2500000000ns"), Ok(Duration::milliseconds(2_500)));
+        assert_eq!(parse_duration("999ms 1ms"), Ok(Duration::seconds(1)));
+        assert_eq!(parse_duration("0s"), Ok(Duration::zero()));
+        assert_eq!(parse_duration("1 Day 2 HOURS"), Ok(Duration::hours(26)));
+        assert_eq!(parse_duration("1m 1MIN 1MS"), Ok(Duration::milliseconds(120_001)));
+        assert_eq!(parse_duration("9223372036854775s"), Ok(Duration::seconds(9223372036854775)));
+
+        assert_eq!(parse_duration(""), Err(Invalid));
+        assert_eq!(parse_duration("   "), Err(Invalid));
+        assert_eq!(parse_duration("5"), Err(Invalid));
+        assert_eq!(parse_duration("h"), Err(Invalid));
+        assert_eq!(parse_duration("-5m"), Err(Invalid));
+        assert_eq!(parse_duration("1.5h"), Err(Invalid));
+        assert_eq!(parse_duration("5 fortnights"), Err(UnknownUnit));
+        assert_eq!(parse_duration("1M"), Err(CalendarUnit));
+        assert_eq!(parse_duration("1 Month"), Err(CalendarUnit));
+        assert_eq!(parse_duration("1 YEARS"), Err(CalendarUnit));
+        assert_eq!(parse_duration("1 year"), Err(CalendarUnit));
+        assert_eq!(parse_duration("9223372036854776s"), Err(OutOfRange));
+        assert_eq!(parse_duration("99999999999999999999s"), Err(OutOfRange));
+        assert_eq!(parse_duration("18446744073709551615w"), Err(OutOfRange));
+    }
+}
@@ -41,6 +41,54 @@
 //! chrono = { version = "0.4", features = ["serde", "rustc-serialize"] }
 //! ```
 //!
+//! The `quickcheck` feature provides [QuickCheck](https://github.com/BurntSushi/quickcheck)
+//! `Arbitrary` implementations for the date and time types,
+//! so that the code using Chrono can be property-tested.
+//! It requires the newer Rust needed by the `quickcheck` crate 1.x.
+//!
+//! The `humanize` feature provides the [`humanize`](./humanize/index.html) module
+//! for displaying durations like "in 2 days" or "3 hours ago".
+//!
+//! The `locales` feature adds `format_localized` methods to the date and time types,
+//! which print the month and weekday names in one of the [`Locale`](./format/enum.Locale.html)s.
+//!
+//! The `i128` feature adds methods for nanosecond timestamps in `i128`,
+//! which cover the whole range of the date and time types.
+//! It requires Rust 1.26 or later.
+//!
+//! The `calendar` feature provides the [`calendar`](./calendar/index.html) module
+//! for converting dates from and to non-Gregorian calendars like the Julian calendar.
+//!
+//! The `tzdb` feature provides the [`tzdb`](./tzdb/index.html) module
+//! with the time zones of the IANA time zone database like `America/New_York`,
+//! which are read from the system tzdata (usually at `/usr/share/zoneinfo`).
+//!
+//! The `protobuf` feature provides the [`protobuf`](./protobuf/index.html) module
+//! for converting from and to the `google.protobuf.Timestamp` and `google.protobuf.Duration`
+//! messages. It requires Rust 1.41 or later.
+//!
+//! The `temporal` feature provides the [`temporal`](./temporal/index.html) module
+//! with the names of the JavaScript Temporal API, like `PlainDate` or `ZonedDateTime`.
+//!
+//! The `rkyv` feature implements [rkyv](https://github.com/rkyv/rkyv)'s
+//! `Archive`, `Serialize` and `Deserialize` for `NaiveDate`, `NaiveTime`, `NaiveDateTime`
+//! and `DateTime` with the `Utc` and `FixedOffset` time zones, for zero-copy serialization.
+//! It requires Rust 1.56 or later.
+//! `Duration` is not covered as it is defined in the `time` crate.
+//! The archived types keep the internal representation private,
+//! so they are only useful for deserializing back into the Chrono types.
+//! They don't implement `CheckBytes` either, which means that the archives cannot be validated:
+//! only pass the bytes serialized by Chrono itself to `rkyv::archived_root`,
+//! as untrusted bytes can deserialize into an invalid `NaiveDate` or `NaiveTime`
+//! whose methods then give wrong results or panic.
+//!
+//! Chrono supports Rust 1.13 or later with the default features and
+//! the `serde` and `rustc-serialize` features, which are checked on CI.
+//! The APIs available without any feature are therefore limited to what Rust 1.13 supports,
+//! so for example Chrono has no `const fn` constructors or associated constants.
+//! Any other optional feature may require a newer Rust, as noted above,
+//! which does not affect the users who don't enable it.
+//!
 //! > Note that Chrono's support for rustc-serialize is now considered deprecated.
 //! Starting from 0.4.0 there is no further guarantee that
 //! the features available in Serde will be also available to rustc-serialize,
@@ -378,16 +426,26 @@ extern crate num;
 extern crate rustc_serialize;
 #[cfg(feature = "serde")]
 extern crate serde as serdelib;
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(test, macro_use)]
+extern crate quickcheck;
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
 
 // this reexport is to aid the transition and should not be in the prelude!
 pub use oldtime::Duration;
 
-#[doc(no_inline)] pub use offset::{TimeZone, Offset, LocalResult, Utc, FixedOffset, Local};
-#[doc(no_inline)] pub use naive::{NaiveDate, IsoWeek, NaiveTime, NaiveDateTime};
+#[doc(no_inline)] pub use offset::{TimeZone, Offset, LocalResult, Utc, FixedOffset, Local, Tai};
+#[doc(no_inline)] pub use naive::{NaiveDate, NaiveTime, NaiveDateTime};
+#[doc(no_inline)] pub use naive::{IsoWeek, IsoWeekDate, OrdinalDate};
+#[doc(no_inline)] pub use naive::{Months, Days, MonthEndPolicy};
 pub use date::{Date, MIN_DATE, MAX_DATE};
-pub use datetime::DateTime;
+pub use datetime::{DateTime, SecondsFormat, MIN_DATETIME, MAX_DATETIME};
 #[cfg(feature = "rustc-serialize")] pub use datetime::rustc_serialize::TsSeconds;
 pub use format::{ParseError, ParseResult};
+pub use round::{DurationRound, RoundingError};
+pub use relative_delta::RelativeDelta;
+pub use weekday_set::{WeekdaySet, WeekdaySetIter};
 
 /// A convenience module appropriate for glob imports (`use chrono::prelude::*;`).
 pub mod prelude {
@@ -397,6 +455,7 @@ pub mod prelude {
     #[doc(no_inline)] pub use {NaiveDate, NaiveTime, NaiveDateTime};
     #[doc(no_inline)] pub use Date;
     #[doc(no_inline)] pub use DateTime;
+    #[doc(no_inline)] pub use DurationRound;
 }
 
 // useful throughout the codebase
@@ -416,13 +475,15 @@ pub mod naive {
     mod internals;
     mod date;
     mod isoweek;
+    mod ordinal;
     mod time;
     mod datetime;
 
-    pub use self::date::{NaiveDate, MIN_DATE, MAX_DATE};
-    pub use self::isoweek::IsoWeek;
+    pub use self::date::{NaiveDate, Months, Days, MonthEndPolicy, MIN_DATE, MAX_DATE};
+    pub use self::isoweek::{IsoWeek, IsoWeekDate};
+    pub use self::ordinal::OrdinalDate;
     pub use self::time::NaiveTime;
-    pub use self::datetime::NaiveDateTime;
+    pub use self::datetime::{NaiveDateTime, MIN_DATETIME, MAX_DATETIME};
     #[cfg(feature = "rustc-serialize")]
     pub use self::datetime::rustc_serialize::TsSeconds;
 
@@ -443,6 +504,19 @@ pub mod naive {
 mod date;
 mod datetime;
 pub mod format;
+mod round;
+mod relative_delta;
+mod weekday_set;
+#[cfg(feature = "humanize")]
+pub mod humanize;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "tzdb")]
+pub mod tzdb;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "temporal")]
+pub mod temporal;
 
 /// Serialization/Deserialization in alternate formats
 ///
@@ -793,12 +867,80 @@ pub trait Datelike: Sized {
     /// The return value ranges from 0 to 365. (The last day of year differs by years.)
     fn ordinal0(&self) -> u32;
 
+    /// Returns the quarter number starting from 1.
+    ///
+    /// The return value ranges from 1 to 4.
+    #[inline]
+    fn quarter(&self) -> u32 {
+        self.month0() / 3 + 1
+    }
+
+    /// Returns the number of days in the month.
+    ///
+    /// The return value ranges from 28 to 31.
+    #[inline]
+    fn days_in_month(&self) -> u32 {
+        match self.month() {
+            2 => if self.is_leap_year() { 29 } else { 28 },
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the number of days in the year.
+    ///
+    /// The return value is either 365 or 366.
+    #[inline]
+    fn days_in_year(&self) -> u32 {
+        if self.is_leap_year() { 366 } else { 365 }
+    }
+
+    /// Returns true if the year is a leap year in the proleptic Gregorian calendar.
+    #[inline]
+    fn is_leap_year(&self) -> bool {
+        let year = self.year();
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
     /// Returns the day of week.
     fn weekday(&self) -> Weekday;
 
     /// Returns the ISO week.
     fn iso_week(&self) -> IsoWeek;
 
+    /// Returns the ordinal of the day of week within the month starting from 1.
+    /// For example, the second Tuesday of a month returns 2.
+    ///
+    /// The return value ranges from 1 to 5.
+    #[inline]
+    fn weekday_ordinal(&self) -> u32 {
+        self.day0() / 7 + 1
+    }
+
+    /// Returns the week of month starting from 1.
+    ///
+    /// Weeks start on Monday, and the first week is the one containing the first day of month,
+    /// so the first week can have less than 7 days.
+    /// The return value ranges from 1 to 6.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// // 2017-06-01 is Thursday
+    /// assert_eq!(NaiveDate::from_ymd(2017, 6, 4).week_of_month(), 1);
+    /// assert_eq!(NaiveDate::from_ymd(2017, 6, 5).week_of_month(), 2);
+    /// assert_eq!(NaiveDate::from_ymd(2017, 6, 5).weekday_ordinal(), 1);
+    /// assert_eq!(NaiveDate::from_ymd(2017, 6, 8).weekday_ordinal(), 2);
+    /// ~~~~
+    #[inline]
+    fn week_of_month(&self) -> u32 {
+        // the number of days from Monday of the first day of month
+        let first = (self.weekday().num_days_from_monday() + 35 - self.day0()) % 7;
+        (self.day0() + first) / 7 + 1
+    }
+
     /// Makes a new value with the year number changed.
     ///
     /// Returns `None` when the resulting value would be invalid.
@@ -848,6 +990,62 @@ pub trait Datelike: Sized {
         ndays += ((year * 1461) >> 2) - div_100 + (div_100 >> 2);
         ndays + self.ordinal() as i32
     }
+
+    /// Makes a new value with the date changed to January 1 of the same year.
+    /// Any time component is kept as is.
+    ///
+    /// Returns `None` when the resulting value would be invalid.
+    #[inline]
+    fn trunc_to_year(&self) -> Option<Self> {
+        self.with_ordinal0(0)
+    }
+
+    /// Makes a new value with the date changed to the first day of the same quarter
+    /// (January 1, April 1, July 1 or October 1).
+    /// Any time component is kept as is.
+    ///
+    /// Returns `None` when the resulting value would be invalid.
+    #[inline]
+    fn trunc_to_quarter(&self) -> Option<Self> {
+        self.with_day0(0).and_then(|d| d.with_month0(self.month0() / 3 * 3))
+    }
+
+    /// Makes a new value with the date changed to the first day of the same month.
+    /// Any time component is kept as is.
+    ///
+    /// Returns `None` when the resulting value would be invalid.
+    #[inline]
+    fn trunc_to_month(&self) -> Option<Self> {
+        self.with_day0(0)
+    }
+
+    /// Makes a new value with the date changed to the Monday of the same ISO week.
+    /// This may be in the previous year.
+    /// Any time component is kept as is.
+    ///
+    /// Returns `None` when the resulting value would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 1, 2);
+    /// assert_eq!(d.trunc_to_iso_week(), Some(NaiveDate::from_ymd(2014, 12, 29)));
+    /// assert_eq!(d.trunc_to_quarter(), Some(NaiveDate::from_ymd(2015, 1, 1)));
+    /// ~~~~
+    fn trunc_to_iso_week(&self) -> Option<Self> {
+        let ordinal0 = self.ordinal0() as i32 - self.weekday().num_days_from_monday() as i32;
+        if ordinal0 >= 0 {
+            self.with_ordinal0(ordinal0 as u32)
+        } else {
+            // the Monday is one of December 26 to 31 in the previous year
+            self.with_ordinal0(0)
+                .and_then(|d| d.with_year(self.year() - 1))
+                .and_then(|d| d.with_month(12))
+                .and_then(|d| d.with_day((32 + ordinal0) as u32))
+        }
+    }
 }
 
 /// The common set of methods for time component.
@@ -3,7 +3,7 @@
 
 //! ISO 8601 calendar date without timezone.
 
-use std::{str, fmt};
+use std::{str, fmt, cmp};
 use std::ops::{Add, Sub, AddAssign, SubAssign};
 use num::traits::ToPrimitive;
 use oldtime::Duration as OldDuration;
@@ -13,6 +13,8 @@ use div::div_mod_floor;
 use naive::{NaiveTime, NaiveDateTime, IsoWeek};
 use format::{Item, Numeric, Pad};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
+#[cfg(feature = "locales")] use format::Locale;
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
 
 use super::isoweek;
 use super::internals::{self, DateImpl, Of, Mdf, YearFlags};
@@ -45,6 +47,12 @@ const MIN_DAYS_FROM_YEAR_0: i32 = (MIN_YEAR + 400_000) * 365 +
 #[cfg(test)] // only used for testing, but duplicated in naive::datetime
 const MAX_BITS: usize = 44;
 
+/// The Julian Day Number of December 31, 1 BCE (day 0 of `num_days_from_ce`).
+const JULIAN_DAY_OF_CE_DAY_0: i32 = 1_721_425;
+
+/// The number of days from December 31, 1 BCE to November 17, 1858 (Modified Julian Day 0).
+const MODIFIED_JULIAN_DAY_0: i32 = 678_576;
+
 /// ISO 8601 calendar date without timezone.
 /// Allows for every [proleptic Gregorian date](#calendar-date)
 /// from Jan 1, 262145 BCE to Dec 31, 262143 CE.
@@ -94,6 +102,7 @@ const MAX_BITS: usize = 44;
 ///
 /// This is currently the internal format of Chrono's date types.
 #[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct NaiveDate {
     ymdf: DateImpl, // (year << 13) | of
 }
@@ -122,6 +131,62 @@ fn test_date_bounds() {
             "The entire `NaiveDate` range somehow exceeds 2^{} seconds", MAX_BITS);
 }
 
+/// A number of calendar months, which can be added to or subtracted from dates.
+///
+/// When the resulting month has less days than the original day of month,
+/// the day is clamped to the last day of that month.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, Months};
+///
+/// let d = NaiveDate::from_ymd(2016, 1, 31);
+/// assert_eq!(d + Months(1), NaiveDate::from_ymd(2016, 2, 29));
+/// assert_eq!(d + Months(13), NaiveDate::from_ymd(2017, 2, 28));
+/// assert_eq!(d - Months(2), NaiveDate::from_ymd(2015, 11, 30));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Months(pub u32);
+
+/// A number of calendar days, which can be added to or subtracted from dates.
+///
+/// Unlike `Duration`, this is not affected by the change of the offset,
+/// so adding `Days(1)` to a local date and time keeps its time of the day.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, Days};
+///
+/// let d = NaiveDate::from_ymd(2016, 2, 28);
+/// assert_eq!(d + Days(1), NaiveDate::from_ymd(2016, 2, 29));
+/// assert_eq!(d - Days(365), NaiveDate::from_ymd(2015, 2, 28));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Days(pub u64);
+
+/// How the whole months between dates are counted
+/// when the day of month is missing in a shorter month,
+/// as in [`NaiveDate::months_since_with`](./struct.NaiveDate.html#method.months_since_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonthEndPolicy {
+    /// A month has passed at the end of a shorter month,
+    /// so January 31 to February 28 counts as one month.
+    ///
+    /// For the years since February 29, including
+    /// [`NaiveDate::age_on`](./struct.NaiveDate.html#method.age_on),
+    /// this means that the anniversary is February 28 in the common years.
+    Clamp,
+    /// A month has passed only when the day of month is reached or exceeded,
+    /// so January 31 to February 28 counts as zero months and to March 1 as one month.
+    ///
+    /// For the years since February 29, including
+    /// [`NaiveDate::age_on`](./struct.NaiveDate.html#method.age_on),
+    /// this means that the anniversary is March 1 in the common years.
+    Strict,
+}
+
 impl NaiveDate {
     /// Makes a new `NaiveDate` from year and packed ordinal-flags, with a verification.
     fn from_of(year: i32, of: Of) -> Option<NaiveDate> {
@@ -330,6 +395,91 @@ impl NaiveDate {
         }
     }
 
+    /// Makes a new `NaiveDate` for the first given day of the week in given month.
+    ///
+    /// Panics on the out-of-range date and/or invalid month.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // the monthly options expire on the third Friday
+    /// let d = NaiveDate::first_weekday_of_month(2019, 3, Weekday::Fri);
+    /// assert_eq!(d + chrono::Duration::weeks(2), NaiveDate::from_ymd(2019, 3, 15));
+    /// ~~~~
+    pub fn first_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+        NaiveDate::first_weekday_of_month_opt(year, month, weekday)
+            .expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `NaiveDate` for the first given day of the week in given month.
+    ///
+    /// Returns `None` on the out-of-range date and/or invalid month.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let first_opt = NaiveDate::first_weekday_of_month_opt;
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(first_opt(2019, 3, Weekday::Fri), Some(from_ymd(2019, 3, 1)));
+    /// assert_eq!(first_opt(2019, 3, Weekday::Mon), Some(from_ymd(2019, 3, 4)));
+    /// assert_eq!(first_opt(2019, 13, Weekday::Mon), None);
+    /// ~~~~
+    pub fn first_weekday_of_month_opt(year: i32, month: u32,
+                                      weekday: Weekday) -> Option<NaiveDate> {
+        let first = try_opt!(NaiveDate::from_ymd_opt(year, month, 1));
+        let days = (weekday.num_days_from_monday() + 7 -
+                    first.weekday().num_days_from_monday()) % 7;
+        NaiveDate::from_ymd_opt(year, month, 1 + days)
+    }
+
+    /// Makes a new `NaiveDate` for the last given day of the week in given month.
+    ///
+    /// Panics on the out-of-range date and/or invalid month.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // payday on the last Friday of the month
+    /// let d = NaiveDate::last_weekday_of_month(2019, 5, Weekday::Fri);
+    /// assert_eq!(d, NaiveDate::from_ymd(2019, 5, 31));
+    /// ~~~~
+    pub fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+        NaiveDate::last_weekday_of_month_opt(year, month, weekday)
+            .expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `NaiveDate` for the last given day of the week in given month.
+    ///
+    /// Returns `None` on the out-of-range date and/or invalid month.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let last_opt = NaiveDate::last_weekday_of_month_opt;
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(last_opt(2019, 5, Weekday::Fri), Some(from_ymd(2019, 5, 31)));
+    /// assert_eq!(last_opt(2019, 5, Weekday::Mon), Some(from_ymd(2019, 5, 27)));
+    /// assert_eq!(last_opt(2019, 0, Weekday::Mon), None);
+    /// ~~~~
+    pub fn last_weekday_of_month_opt(year: i32, month: u32,
+                                     weekday: Weekday) -> Option<NaiveDate> {
+        let first = try_opt!(NaiveDate::from_ymd_opt(year, month, 1));
+        let last = try_opt!(NaiveDate::from_ymd_opt(year, month, first.days_in_month()));
+        let days = (last.weekday().num_days_from_monday() + 7 -
+                    weekday.num_days_from_monday()) % 7;
+        NaiveDate::from_ymd_opt(year, month, last.day() - days)
+    }
+
     /// Makes a new `NaiveDate` from the number of days since January 1, 1 (Day 1)
     /// in the proleptic Gregorian calendar.
     ///
@@ -407,6 +557,56 @@ impl NaiveDate {
                            Of::new(ordinal, flags))
     }
 
+    /// Makes a new `NaiveDate` from the Julian Day Number,
+    /// i.e. the number of days since January 1, 4713 BCE in the proleptic Julian calendar.
+    ///
+    /// Panics on the out-of-range date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_julian_day(2_451_545), NaiveDate::from_ymd(2000, 1, 1));
+    /// assert_eq!(NaiveDate::from_julian_day(0), NaiveDate::from_ymd(-4713, 11, 24));
+    /// ~~~~
+    pub fn from_julian_day(jdn: i32) -> NaiveDate {
+        NaiveDate::from_julian_day_opt(jdn).expect("out-of-range date")
+    }
+
+    /// Makes a new `NaiveDate` from the Julian Day Number,
+    /// i.e. the number of days since January 1, 4713 BCE in the proleptic Julian calendar.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn from_julian_day_opt(jdn: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(jdn.checked_sub(JULIAN_DAY_OF_CE_DAY_0)))
+    }
+
+    /// Makes a new `NaiveDate` from the Modified Julian Day,
+    /// i.e. the number of days since November 17, 1858.
+    ///
+    /// Panics on the out-of-range date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_modified_julian_day(51_544), NaiveDate::from_ymd(2000, 1, 1));
+    /// assert_eq!(NaiveDate::from_modified_julian_day(0), NaiveDate::from_ymd(1858, 11, 17));
+    /// ~~~~
+    pub fn from_modified_julian_day(mjd: i32) -> NaiveDate {
+        NaiveDate::from_modified_julian_day_opt(mjd).expect("out-of-range date")
+    }
+
+    /// Makes a new `NaiveDate` from the Modified Julian Day,
+    /// i.e. the number of days since November 17, 1858.
+    ///
+    /// Returns `None` on the out-of-range date.
+    pub fn from_modified_julian_day_opt(mjd: i32) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(mjd.checked_add(MODIFIED_JULIAN_DAY_0)))
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -853,6 +1053,113 @@ impl NaiveDate {
                            Of::new(ordinal, flags))
     }
 
+    /// Adds given number of months to the current date,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Months};
+    /// use chrono::naive::MAX_DATE;
+    ///
+    /// let d = NaiveDate::from_ymd(2016, 8, 31);
+    /// assert_eq!(d.checked_add_months(Months(1)), Some(NaiveDate::from_ymd(2016, 9, 30)));
+    /// assert_eq!(d.checked_add_months(Months(6)), Some(NaiveDate::from_ymd(2017, 2, 28)));
+    /// assert_eq!(MAX_DATE.checked_add_months(Months(1)), None);
+    /// ~~~~
+    pub fn checked_add_months(self, months: Months) -> Option<NaiveDate> {
+        self.diff_months(months.0 as i64)
+    }
+
+    /// Subtracts given number of months from the current date,
+    /// clamping the day to the last day of the resulting month if needed.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Months};
+    /// use chrono::naive::MIN_DATE;
+    ///
+    /// let d = NaiveDate::from_ymd(2016, 3, 31);
+    /// assert_eq!(d.checked_sub_months(Months(1)), Some(NaiveDate::from_ymd(2016, 2, 29)));
+    /// assert_eq!(d.checked_sub_months(Months(12)), Some(NaiveDate::from_ymd(2015, 3, 31)));
+    /// assert_eq!(MIN_DATE.checked_sub_months(Months(1)), None);
+    /// ~~~~
+    pub fn checked_sub_months(self, months: Months) -> Option<NaiveDate> {
+        self.diff_months(-(months.0 as i64))
+    }
+
+    /// Adds given number of days to the current date.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_add_days(self, days: Days) -> Option<NaiveDate> {
+        let days = try_opt!(days.0.to_i32());
+        self.checked_add_signed(OldDuration::days(days as i64))
+    }
+
+    /// Subtracts given number of days from the current date.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_sub_days(self, days: Days) -> Option<NaiveDate> {
+        let days = try_opt!(days.0.to_i32());
+        self.checked_sub_signed(OldDuration::days(days as i64))
+    }
+
+    /// Moves the date by given (possibly negative) number of months.
+    fn diff_months(self, months: i64) -> Option<NaiveDate> {
+        let months = self.year() as i64 * 12 + self.month0() as i64 + months;
+        let (year, month0) = div_mod_floor(months, 12);
+        let year = try_opt!(year.to_i32());
+        let month = month0 as u32 + 1;
+
+        // the last day of the month is the only day that can be invalid here
+        let mut day = self.day();
+        loop {
+            if let Some(d) = NaiveDate::from_ymd_opt(year, month, day) {
+                return Some(d);
+            }
+            if day <= 28 { return None; } // the year itself is out of range
+            day -= 1;
+        }
+    }
+
+    /// Returns the Julian Day Number of this date,
+    /// i.e. the number of days since January 1, 4713 BCE in the proleptic Julian calendar.
+    ///
+    /// Strictly speaking the Julian day starts at noon,
+    /// so this is the Julian Day Number of the noon of this date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).to_julian_day(), 2_451_545);
+    /// ~~~~
+    #[inline]
+    pub fn to_julian_day(&self) -> i32 {
+        self.num_days_from_ce() + JULIAN_DAY_OF_CE_DAY_0
+    }
+
+    /// Returns the Modified Julian Day of this date,
+    /// i.e. the number of days since November 17, 1858.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).to_modified_julian_day(), 51_544);
+    /// ~~~~
+    #[inline]
+    pub fn to_modified_julian_day(&self) -> i32 {
+        self.num_days_from_ce() - MODIFIED_JULIAN_DAY_0
+    }
+
     /// Subtracts another `NaiveDate` from the current date.
     /// Returns a `Duration` of integral numbers.
     ///
@@ -889,6 +1196,114 @@ impl NaiveDate {
                           (cycle1 - cycle2))
     }
 
+    /// Returns the number of whole months from `base` to the current date,
+    /// which is negative when the current date is earlier than `base`.
+    ///
+    /// A month has passed when the day of month of `base` is reached,
+    /// or the end of the month if that month is shorter.
+    /// This is consistent with adding [`Months`](./struct.Months.html),
+    /// so January 31 to February 28 counts as one month.
+    /// See [`months_since_with`](#method.months_since_with) for the other policy.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    /// assert_eq!(from_ymd(2014, 3, 14).months_since(from_ymd(2014, 1, 15)), 1);
+    /// assert_eq!(from_ymd(2014, 3, 15).months_since(from_ymd(2014, 1, 15)), 2);
+    /// assert_eq!(from_ymd(2014, 2, 28).months_since(from_ymd(2014, 1, 31)), 1);
+    /// assert_eq!(from_ymd(2013, 11, 16).months_since(from_ymd(2014, 1, 15)), -1);
+    /// ~~~~
+    pub fn months_since(self, base: NaiveDate) -> i32 {
+        self.months_since_with(base, MonthEndPolicy::Clamp)
+    }
+
+    /// Returns the number of whole months from `base` to the current date
+    /// with given policy for the days missing in shorter months.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, MonthEndPolicy};
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    /// let jan31 = from_ymd(2014, 1, 31);
+    /// let (feb28, mar1) = (from_ymd(2014, 2, 28), from_ymd(2014, 3, 1));
+    /// assert_eq!(feb28.months_since_with(jan31, MonthEndPolicy::Clamp), 1);
+    /// assert_eq!(feb28.months_since_with(jan31, MonthEndPolicy::Strict), 0);
+    /// assert_eq!(mar1.months_since_with(jan31, MonthEndPolicy::Strict), 1);
+    /// ~~~~
+    pub fn months_since_with(self, base: NaiveDate, policy: MonthEndPolicy) -> i32 {
+        let months = (self.year() - base.year()) * 12 + self.month() as i32 - base.month() as i32;
+        let target = match policy {
+            MonthEndPolicy::Clamp => cmp::min(base.day(), self.days_in_month()),
+            MonthEndPolicy::Strict => base.day(),
+        };
+        if self >= base && self.day() < target {
+            months - 1
+        } else if self < base && self.day() > target {
+            months + 1
+        } else {
+            months
+        }
+    }
+
+    /// Returns the number of whole years from `base` to the current date,
+    /// which is negative when the current date is earlier than `base`.
+    ///
+    /// A year has passed when the month and day of `base` is reached.
+    /// From February 29, it is February 28 in the common years;
+    /// see [`years_since_with`](#method.years_since_with) for the other policy.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    /// assert_eq!(from_ymd(2014, 3, 14).years_since(from_ymd(2004, 3, 15)), 9);
+    /// assert_eq!(from_ymd(2014, 3, 15).years_since(from_ymd(2004, 3, 15)), 10);
+    /// assert_eq!(from_ymd(2017, 2, 28).years_since(from_ymd(2016, 2, 29)), 1);
+    /// ~~~~
+    pub fn years_since(self, base: NaiveDate) -> i32 {
+        self.months_since(base) / 12
+    }
+
+    /// Returns the number of whole years from `base` to the current date
+    /// with given policy for February 29 in the common years.
+    pub fn years_since_with(self, base: NaiveDate, policy: MonthEndPolicy) -> i32 {
+        self.months_since_with(base, policy) / 12
+    }
+
+    /// Returns the age on `as_of` of someone born on the current date,
+    /// or `None` if `as_of` is before the birth.
+    ///
+    /// The birthday of someone born on February 29 is
+    /// February 28 in the common years with `MonthEndPolicy::Clamp`,
+    /// and March 1 with `MonthEndPolicy::Strict`.
+    /// The policy makes no difference for other birthdays.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, MonthEndPolicy};
+    ///
+    /// let birth = NaiveDate::from_ymd(2000, 2, 29);
+    /// let as_of = NaiveDate::from_ymd(2018, 2, 28);
+    /// assert_eq!(birth.age_on(as_of, MonthEndPolicy::Clamp), Some(18));
+    /// assert_eq!(birth.age_on(as_of, MonthEndPolicy::Strict), Some(17));
+    /// assert_eq!(birth.age_on(NaiveDate::from_ymd(1999, 1, 1), MonthEndPolicy::Clamp), None);
+    /// ~~~~
+    pub fn age_on(self, as_of: NaiveDate, policy: MonthEndPolicy) -> Option<u32> {
+        if as_of < self {
+            None
+        } else {
+            Some(as_of.years_since_with(self, policy) as u32)
+        }
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is same to the ordinary `format` method.
     ///
@@ -958,6 +1373,37 @@ impl NaiveDate {
     pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_with_items(StrftimeItems::new(fmt))
     }
+
+    /// Formats the date with the specified formatting items,
+    /// printing the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized_with_items<'a, I>(&self, items: I, locale: Locale) -> DelayedFormat<I>
+            where I: Iterator<Item=Item<'a>> + Clone {
+        DelayedFormat::new_with_locale(Some(*self), None, items, locale)
+    }
+
+    /// Formats the date with the specified format string,
+    /// printing the month and weekday names in given locale.
+    /// See [`format`](#method.format) for other details.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::format::Locale;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.format_localized("%A %-d %B %Y", Locale::French).to_string(),
+    ///            "samedi 5 septembre 2015");
+    /// assert_eq!(d.format_localized("%a, %-d. %b", Locale::German).to_string(), "Sa, 5. Sep");
+    /// ~~~~
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized<'a>(&self, fmt: &'a str,
+                                locale: Locale) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_localized_with_items(StrftimeItems::new(fmt), locale)
+    }
 }
 
 impl Datelike for NaiveDate {
@@ -1123,6 +1569,26 @@ impl Datelike for NaiveDate {
         self.of().ordinal() - 1
     }
 
+    /// Returns true if the year is a leap year in the proleptic Gregorian calendar.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert!(!NaiveDate::from_ymd(2015, 9, 8).is_leap_year());
+    /// assert!(NaiveDate::from_ymd(2016, 9, 8).is_leap_year());
+    /// assert!(!NaiveDate::from_ymd(1900, 9, 8).is_leap_year());
+    /// assert!(NaiveDate::from_ymd(2000, 9, 8).is_leap_year());
+    /// assert_eq!(NaiveDate::from_ymd(2016, 9, 8).days_in_year(), 366);
+    /// assert_eq!(NaiveDate::from_ymd(2016, 9, 8).days_in_month(), 30);
+    /// assert_eq!(NaiveDate::from_ymd(2016, 9, 8).quarter(), 3);
+    /// ~~~~
+    #[inline]
+    fn is_leap_year(&self) -> bool {
+        self.of().flags().ndays() == 366
+    }
+
     /// Returns the day of week.
     ///
     /// # Example
@@ -1385,6 +1851,42 @@ impl SubAssign<OldDuration> for NaiveDate {
     }
 }
 
+impl Add<Months> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn add(self, rhs: Months) -> NaiveDate {
+        self.checked_add_months(rhs).expect("`NaiveDate + Months` out of range")
+    }
+}
+
+impl Sub<Months> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> NaiveDate {
+        self.checked_sub_months(rhs).expect("`NaiveDate - Months` out of range")
+    }
+}
+
+impl Add<Days> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn add(self, rhs: Days) -> NaiveDate {
+        self.checked_add_days(rhs).expect("`NaiveDate + Days` out of range")
+    }
+}
+
+impl Sub<Days> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> NaiveDate {
+        self.checked_sub_days(rhs).expect("`NaiveDate - Days` out of range")
+    }
+}
+
 /// The `Debug` output of the naive date `d` is same to
 /// [`d.format("%Y-%m-%d")`](../format/strftime/index.html).
 ///
@@ -1639,13 +2141,38 @@ mod serde {
     }
 }
 
+#[cfg(feature = "quickcheck")]
+mod arbitrary {
+    use super::{NaiveDate, MIN_DATE, MAX_DATE};
+    use quickcheck::{Arbitrary, Gen};
+    use div::mod_floor;
+    use Datelike;
+
+    /// Generates a date uniformly distributed over the whole supported range.
+    impl Arbitrary for NaiveDate {
+        fn arbitrary(g: &mut Gen) -> NaiveDate {
+            let min = MIN_DATE.num_days_from_ce() as i64;
+            let ndays = MAX_DATE.num_days_from_ce() as i64 - min + 1;
+            let days = min + mod_floor(i64::arbitrary(g), ndays);
+            NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap()
+        }
+    }
+
+    #[cfg(test)]
+    quickcheck! {
+        fn prop_string_roundtrip(d: NaiveDate) -> bool {
+            d.to_string().parse::<NaiveDate>() == Ok(d)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::NaiveDate;
+    use super::{NaiveDate, Months, Days};
     use super::{MIN_DATE, MIN_YEAR, MIN_DAYS_FROM_YEAR_0};
     use super::{MAX_DATE, MAX_YEAR, MAX_DAYS_FROM_YEAR_0};
     use {Datelike, Weekday};
-    use std::{i32, u32};
+    use std::{i32, u32, u64};
     use oldtime::Duration;
 
     #[test]
@@ -1756,6 +2283,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_weekday_of_month() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        let first_opt = |y,m,w| NaiveDate::first_weekday_of_month_opt(y, m, w);
+        let last_opt = |y,m,w| NaiveDate::last_weekday_of_month_opt(y, m, w);
+
+        assert_eq!(first_opt(2019, 2, Weekday::Fri), Some(ymd(2019, 2, 1)));
+        assert_eq!(first_opt(2019, 2, Weekday::Thu), Some(ymd(2019, 2, 7)));
+        assert_eq!(last_opt(2019, 2, Weekday::Thu), Some(ymd(2019, 2, 28)));
+        assert_eq!(last_opt(2019, 2, Weekday::Fri), Some(ymd(2019, 2, 22)));
+        assert_eq!(last_opt(2020, 2, Weekday::Sat), Some(ymd(2020, 2, 29)));
+        assert_eq!(first_opt(2019, 13, Weekday::Mon), None);
+        assert_eq!(last_opt(2019, 0, Weekday::Mon), None);
+
+        for year in 2000..2029 {
+            for month in 1..13 {
+                for &weekday in [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+                                 Weekday::Fri, Weekday::Sat, Weekday::Sun].iter() {
+                    let first = NaiveDate::first_weekday_of_month(year, month, weekday);
+                    let last = NaiveDate::last_weekday_of_month(year, month, weekday);
+                    assert_eq!((first.weekday(), last.weekday()), (weekday, weekday));
+                    assert_eq!((first.month(), last.month()), (month, month));
+                    assert!(first.day() <= 7);
+                    assert!(last.day() + 7 > last.days_in_month());
+                }
+            }
+        }
+
+        assert_eq!(first_opt(MIN_DATE.year(), 1, MIN_DATE.weekday()), Some(MIN_DATE));
+        assert_eq!(last_opt(MAX_DATE.year(), 12, MAX_DATE.weekday()), Some(MAX_DATE));
+        assert_eq!(first_opt(MAX_DATE.year() + 1, 1, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_date_julian_day() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        for &(date, jdn, mjd) in &[(ymd(2000, 1, 1), 2_451_545, 51_544),
+                                   (ymd(1858, 11, 17), 2_400_001, 0),
+                                   (ymd(1, 1, 1), 1_721_426, -678_575),
+                                   (ymd(-4713, 11, 24), 0, -2_400_001)] {
+            assert_eq!(date.to_julian_day(), jdn);
+            assert_eq!(date.to_modified_julian_day(), mjd);
+            assert_eq!(NaiveDate::from_julian_day_opt(jdn), Some(date));
+            assert_eq!(NaiveDate::from_modified_julian_day_opt(mjd), Some(date));
+        }
+
+        for &date in &[MIN_DATE, MAX_DATE] {
+            assert_eq!(NaiveDate::from_julian_day_opt(date.to_julian_day()), Some(date));
+            assert_eq!(NaiveDate::from_modified_julian_day_opt(date.to_modified_julian_day()),
+                       Some(date));
+        }
+        assert_eq!(NaiveDate::from_julian_day_opt(MIN_DATE.to_julian_day() - 1), None);
+        assert_eq!(NaiveDate::from_julian_day_opt(MAX_DATE.to_julian_day() + 1), None);
+        assert_eq!(NaiveDate::from_julian_day_opt(i32::MIN), None);
+        assert_eq!(NaiveDate::from_modified_julian_day_opt(i32::MAX), None);
+    }
+
     #[test]
     fn test_date_from_num_days_from_ce() {
         let from_ndays_from_ce = |days| NaiveDate::from_num_days_from_ce_opt(days);
@@ -1883,6 +2467,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date_period_fields() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2000, 1, 1).quarter(), 1);
+        assert_eq!(ymd(2000, 3, 31).quarter(), 1);
+        assert_eq!(ymd(2000, 4, 1).quarter(), 2);
+        assert_eq!(ymd(2000, 12, 31).quarter(), 4);
+
+        assert_eq!(ymd(2000, 1, 15).days_in_month(), 31);
+        assert_eq!(ymd(2000, 2, 15).days_in_month(), 29);
+        assert_eq!(ymd(1900, 2, 15).days_in_month(), 28);
+        assert_eq!(ymd(2001, 2, 15).days_in_month(), 28);
+        assert_eq!(ymd(-400, 2, 15).days_in_month(), 29);
+        assert_eq!(ymd(2001, 4, 15).days_in_month(), 30);
+        assert_eq!(ymd(2001, 12, 15).days_in_month(), 31);
+
+        for year in -1000..3000 {
+            let d = ymd(year, 1, 1);
+            assert_eq!(d.days_in_year(), ymd(year, 12, 31).ordinal());
+            // the default method agrees with the year flags
+            assert_eq!(d.and_hms(0, 0, 0).is_leap_year(), d.is_leap_year());
+            assert_eq!(ymd(year, 2, 1).days_in_month(), ymd(year, 3, 1).pred().day());
+        }
+    }
+
+    #[test]
+    fn test_date_weeks_of_month() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        // 2018-01-01 is Monday, 2018-07-01 is Sunday
+        assert_eq!(ymd(2018, 1, 1).week_of_month(), 1);
+        assert_eq!(ymd(2018, 1, 7).week_of_month(), 1);
+        assert_eq!(ymd(2018, 1, 8).week_of_month(), 2);
+        assert_eq!(ymd(2018, 1, 31).week_of_month(), 5);
+        assert_eq!(ymd(2018, 7, 1).week_of_month(), 1);
+        assert_eq!(ymd(2018, 7, 2).week_of_month(), 2);
+        assert_eq!(ymd(2018, 7, 31).week_of_month(), 6);
+
+        assert_eq!(ymd(2018, 7, 1).weekday_ordinal(), 1);
+        assert_eq!(ymd(2018, 7, 7).weekday_ordinal(), 1);
+        assert_eq!(ymd(2018, 7, 8).weekday_ordinal(), 2);
+        assert_eq!(ymd(2018, 7, 29).weekday_ordinal(), 5);
+
+        // the second Tuesday of each month
+        for m in 1..13 {
+            let tuesdays: Vec<_> = (1..ymd(2018, m, 1).days_in_month() + 1)
+                .map(|d| ymd(2018, m, d))
+                .filter(|d| d.weekday() == Weekday::Tue)
+                .collect();
+            for (i, d) in tuesdays.iter().enumerate() {
+                assert_eq!(d.weekday_ordinal(), i as u32 + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2000, 5, 17).trunc_to_year(), Some(ymd(2000, 1, 1)));
+        assert_eq!(ymd(2000, 5, 17).trunc_to_quarter(), Some(ymd(2000, 4, 1)));
+        assert_eq!(ymd(2000, 12, 31).trunc_to_quarter(), Some(ymd(2000, 10, 1)));
+        assert_eq!(ymd(2000, 5, 17).trunc_to_month(), Some(ymd(2000, 5, 1)));
+        assert_eq!(ymd(2000, 5, 17).trunc_to_iso_week(), Some(ymd(2000, 5, 15)));
+        assert_eq!(ymd(2000, 5, 15).trunc_to_iso_week(), Some(ymd(2000, 5, 15)));
+        assert_eq!(ymd(2010, 1, 3).trunc_to_iso_week(), Some(ymd(2009, 12, 28)));
+        assert_eq!(ymd(2017, 1, 1).trunc_to_iso_week(), Some(ymd(2016, 12, 26)));
+
+        for d in (0..800).map(|n| ymd(2014, 1, 1) + Duration::days(n)) {
+            let monday = d.trunc_to_iso_week().unwrap();
+            assert_eq!(monday.weekday(), Weekday::Mon);
+            assert_eq!(monday.iso_week().year(), d.iso_week().year());
+            assert_eq!(monday.iso_week().week(), d.iso_week().week());
+        }
+
+        assert_eq!(MIN_DATE.trunc_to_iso_week(), None);
+    }
+
     #[test]
     fn test_date_succ() {
         let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
@@ -1933,6 +2593,95 @@ mod tests {
         check((0, 1, 1), Duration::min_value(), None);
     }
 
+    #[test]
+    fn test_date_add_months() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2014, 1, 31).checked_add_months(Months(0)), Some(ymd(2014, 1, 31)));
+        assert_eq!(ymd(2014, 1, 31).checked_add_months(Months(1)), Some(ymd(2014, 2, 28)));
+        assert_eq!(ymd(2016, 1, 31).checked_add_months(Months(1)), Some(ymd(2016, 2, 29)));
+        assert_eq!(ymd(2014, 3, 31).checked_add_months(Months(1)), Some(ymd(2014, 4, 30)));
+        assert_eq!(ymd(2014, 11, 15).checked_add_months(Months(14)), Some(ymd(2016, 1, 15)));
+        assert_eq!(ymd(2014, 3, 31).checked_sub_months(Months(1)), Some(ymd(2014, 2, 28)));
+        assert_eq!(ymd(2014, 1, 15).checked_sub_months(Months(13)), Some(ymd(2012, 12, 15)));
+        assert_eq!(ymd(2016, 2, 29) + Months(12), ymd(2017, 2, 28));
+        assert_eq!(ymd(2016, 2, 29) - Months(48), ymd(2012, 2, 29));
+
+        // overflow check
+        assert_eq!(MAX_DATE.checked_add_months(Months(1)), None);
+        assert_eq!(MIN_DATE.checked_sub_months(Months(1)), None);
+        assert_eq!(ymd(2014, 1, 1).checked_add_months(Months(u32::MAX)), None);
+    }
+
+    #[test]
+    fn test_date_months_since() {
+        use super::MonthEndPolicy::{Clamp, Strict};
+
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2014, 1, 31).months_since(ymd(2014, 1, 31)), 0);
+        assert_eq!(ymd(2014, 2, 27).months_since(ymd(2014, 1, 28)), 0);
+        assert_eq!(ymd(2014, 2, 28).months_since(ymd(2014, 1, 28)), 1);
+        assert_eq!(ymd(2014, 2, 28).months_since(ymd(2014, 1, 31)), 1);
+        assert_eq!(ymd(2016, 2, 28).months_since(ymd(2016, 1, 31)), 0);
+        assert_eq!(ymd(2016, 2, 29).months_since(ymd(2016, 1, 31)), 1);
+        assert_eq!(ymd(2014, 3, 30).months_since(ymd(2014, 1, 31)), 1);
+        assert_eq!(ymd(2015, 1, 30).months_since(ymd(2014, 1, 31)), 11);
+        assert_eq!(ymd(2014, 2, 28).months_since(ymd(2014, 3, 31)), -1);
+        assert_eq!(ymd(2014, 3, 1).months_since(ymd(2014, 3, 31)), 0);
+        assert_eq!(ymd(2013, 12, 31).months_since(ymd(2014, 3, 31)), -3);
+        assert_eq!(ymd(2014, 2, 28).months_since_with(ymd(2014, 1, 31), Strict), 0);
+        assert_eq!(ymd(2014, 3, 1).months_since_with(ymd(2014, 1, 31), Strict), 1);
+        assert_eq!(ymd(2014, 1, 31).months_since_with(ymd(2014, 3, 1), Strict), -1);
+        assert_eq!(ymd(2014, 2, 28).months_since_with(ymd(2014, 3, 31), Strict), -1);
+        assert_eq!(MAX_DATE.months_since(MIN_DATE), 524_287 * 12 + 11);
+        assert_eq!(MIN_DATE.months_since(MAX_DATE), -(524_287 * 12 + 11));
+
+        assert_eq!(ymd(2014, 3, 14).years_since(ymd(2004, 3, 15)), 9);
+        assert_eq!(ymd(2004, 3, 16).years_since(ymd(2014, 3, 15)), -9);
+        assert_eq!(ymd(2017, 2, 28).years_since(ymd(2016, 2, 29)), 1);
+        assert_eq!(ymd(2017, 2, 28).years_since_with(ymd(2016, 2, 29), Clamp), 1);
+        assert_eq!(ymd(2017, 2, 28).years_since_with(ymd(2016, 2, 29), Strict), 0);
+        assert_eq!(ymd(2017, 3, 1).years_since_with(ymd(2016, 2, 29), Strict), 1);
+        assert_eq!(ymd(2020, 2, 28).years_since_with(ymd(2016, 2, 29), Strict), 3);
+        assert_eq!(ymd(2020, 2, 29).years_since_with(ymd(2016, 2, 29), Strict), 4);
+    }
+
+    #[test]
+    fn test_date_age_on() {
+        use super::MonthEndPolicy::{Clamp, Strict};
+
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        let birth = ymd(1990, 7, 14);
+        assert_eq!(birth.age_on(birth, Clamp), Some(0));
+        assert_eq!(birth.age_on(ymd(2018, 7, 13), Clamp), Some(27));
+        assert_eq!(birth.age_on(ymd(2018, 7, 14), Strict), Some(28));
+        assert_eq!(birth.age_on(ymd(1990, 7, 13), Clamp), None);
+
+        let leap = ymd(2000, 2, 29);
+        assert_eq!(leap.age_on(ymd(2001, 2, 27), Clamp), Some(0));
+        assert_eq!(leap.age_on(ymd(2001, 2, 28), Clamp), Some(1));
+        assert_eq!(leap.age_on(ymd(2001, 2, 28), Strict), Some(0));
+        assert_eq!(leap.age_on(ymd(2001, 3, 1), Strict), Some(1));
+        assert_eq!(leap.age_on(ymd(2004, 2, 28), Clamp), Some(3));
+        assert_eq!(leap.age_on(ymd(2004, 2, 29), Clamp), Some(4));
+        assert_eq!(leap.age_on(ymd(2100, 2, 28), Clamp), Some(100));
+        assert_eq!(leap.age_on(ymd(2100, 2, 28), Strict), Some(99));
+    }
+
+    #[test]
+    fn test_date_add_days() {
+        let ymd = |y,m,d| NaiveDate::from_ymd(y, m, d);
+        assert_eq!(ymd(2014, 1, 31).checked_add_days(Days(0)), Some(ymd(2014, 1, 31)));
+        assert_eq!(ymd(2014, 1, 31).checked_add_days(Days(1)), Some(ymd(2014, 2, 1)));
+        assert_eq!(ymd(2014, 1, 1).checked_sub_days(Days(1)), Some(ymd(2013, 12, 31)));
+        assert_eq!(ymd(2014, 1, 1) + Days(365), ymd(2015, 1, 1));
+        assert_eq!(ymd(2014, 1, 1) - Days(365), ymd(2013, 1, 1));
+
+        // overflow check
+        assert_eq!(MAX_DATE.checked_add_days(Days(1)), None);
+        assert_eq!(MIN_DATE.checked_sub_days(Days(1)), None);
+        assert_eq!(ymd(2014, 1, 1).checked_add_days(Days(u64::MAX)), None);
+    }
+
     #[test]
     fn test_date_sub() {
         fn check((y1,m1,d1): (i32, u32, u32), (y2,m2,d2): (i32, u32, u32), diff: Duration) {
@@ -2046,6 +2795,16 @@ mod tests {
         assert!(NaiveDate::parse_from_str("Sat, 09 Aug 2013", "%a, %d %b %Y").is_err());
         assert!(NaiveDate::parse_from_str("2014-57", "%Y-%m-%d").is_err());
         assert!(NaiveDate::parse_from_str("2014", "%Y").is_err()); // insufficient
+        assert_eq!(NaiveDate::parse_from_str("2018-08 W2 1", "%Y-%m W%i %u"),
+                   Ok(ymd(2018, 8, 6)));
+        assert_eq!(NaiveDate::parse_from_str("Q3 2018-08-06", "%Q %F"), Ok(ymd(2018, 8, 6)));
+        assert!(NaiveDate::parse_from_str("Q2 2018-08-06", "%Q %F").is_err());
+
+        // quarters and weeks of the month round-trip
+        let d = ymd(2018, 9, 30);
+        let s = d.format("%Y-%m Q%q W%i %u").to_string();
+        assert_eq!(s, "2018-09 Q3 W5 7");
+        assert_eq!(NaiveDate::parse_from_str(&s, "%Y-%m Q%q W%i %u"), Ok(d));
     }
 
     #[test]
@@ -2057,6 +2816,7 @@ mod tests {
         assert_eq!(d.format("%U,%W,%V").to_string(), "10,09,09");
         assert_eq!(d.format("%a,%A,%w,%u").to_string(), "Sun,Sunday,0,7");
         assert_eq!(d.format("%j").to_string(), "064"); // since 2012 is a leap year
+        assert_eq!(d.format("%q,%Q,%i").to_string(), "1,Q1,1");
         assert_eq!(d.format("%D,%x").to_string(), "03/04/12,03/04/12");
         assert_eq!(d.format("%F").to_string(), "2012-03-04");
         assert_eq!(d.format("%v").to_string(), " 4-Mar-2012");
@@ -10,9 +10,12 @@ use oldtime::Duration as OldDuration;
 
 use {Weekday, Timelike, Datelike};
 use div::div_mod_floor;
-use naive::{NaiveTime, NaiveDate, IsoWeek};
+use naive::{NaiveTime, NaiveDate, IsoWeek, Months, Days, MIN_DATE, MAX_DATE};
+use naive::time::{MIN_TIME, MAX_TIME};
 use format::{Item, Numeric, Pad, Fixed};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
+#[cfg(feature = "locales")] use format::Locale;
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
 
 /// The tight upper bound guarantees that a duration with `|Duration| >= 2^MAX_SECS_BITS`
 /// will always overflow the addition with any date and time type.
@@ -22,6 +25,9 @@ use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItem
 /// touching that call when we are already sure that it WILL overflow...
 const MAX_SECS_BITS: usize = 44;
 
+/// The Julian Date of the Modified Julian Date 0, i.e. the midnight of November 17, 1858.
+const MODIFIED_JULIAN_DATE_0: f64 = 2_400_000.5;
+
 /// ISO 8601 combined date and time without timezone.
 ///
 /// # Example
@@ -48,11 +54,18 @@ const MAX_SECS_BITS: usize = 44;
 /// assert_eq!(dt.num_seconds_from_midnight(), 33011);
 /// ~~~~
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct NaiveDateTime {
     date: NaiveDate,
     time: NaiveTime,
 }
 
+/// The minimum possible `NaiveDateTime` (the midnight of January 1, 262145 BCE).
+pub const MIN_DATETIME: NaiveDateTime = NaiveDateTime { date: MIN_DATE, time: MIN_TIME };
+/// The maximum possible `NaiveDateTime` (the last nanosecond of December 31, 262143 CE),
+/// excluding leap seconds.
+pub const MAX_DATETIME: NaiveDateTime = NaiveDateTime { date: MAX_DATE, time: MAX_TIME };
+
 impl NaiveDateTime {
     /// Makes a new `NaiveDateTime` from date and time components.
     /// Equivalent to [`date.and_time(time)`](./struct.NaiveDate.html#method.and_time)
@@ -143,6 +156,215 @@ impl NaiveDateTime {
         }
     }
 
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap milliseconds
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Panics on the out-of-range number of milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// let dt = NaiveDateTime::from_timestamp_millis(1_000_000_000_555);
+    /// assert_eq!(dt, NaiveDate::from_ymd(2001, 9, 9).and_hms_milli(1, 46, 40, 555));
+    ///
+    /// let dt = NaiveDateTime::from_timestamp_millis(-1);
+    /// assert_eq!(dt, NaiveDate::from_ymd(1969, 12, 31).and_hms_milli(23, 59, 59, 999));
+    /// ~~~~
+    #[inline]
+    pub fn from_timestamp_millis(millis: i64) -> NaiveDateTime {
+        let datetime = NaiveDateTime::from_timestamp_millis_opt(millis);
+        datetime.expect("out-of-range datetime")
+    }
+
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap milliseconds
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Returns `None` on the out-of-range number of milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDateTime;
+    /// use std::i64;
+    ///
+    /// assert!(NaiveDateTime::from_timestamp_millis_opt(1_000_000_000_555).is_some());
+    /// assert!(NaiveDateTime::from_timestamp_millis_opt(i64::MAX).is_none());
+    /// ~~~~
+    #[inline]
+    pub fn from_timestamp_millis_opt(millis: i64) -> Option<NaiveDateTime> {
+        let (secs, millis) = div_mod_floor(millis, 1_000);
+        NaiveDateTime::from_timestamp_opt(secs, millis as u32 * 1_000_000)
+    }
+
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap microseconds
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Panics on the out-of-range number of microseconds.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// let dt = NaiveDateTime::from_timestamp_micros(1_000_000_000_555_666);
+    /// assert_eq!(dt, NaiveDate::from_ymd(2001, 9, 9).and_hms_micro(1, 46, 40, 555_666));
+    /// ~~~~
+    #[inline]
+    pub fn from_timestamp_micros(micros: i64) -> NaiveDateTime {
+        let datetime = NaiveDateTime::from_timestamp_micros_opt(micros);
+        datetime.expect("out-of-range datetime")
+    }
+
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap microseconds
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Returns `None` on the out-of-range number of microseconds.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDateTime;
+    /// use std::i64;
+    ///
+    /// assert!(NaiveDateTime::from_timestamp_micros_opt(1_000_000_000_555_666).is_some());
+    /// assert!(NaiveDateTime::from_timestamp_micros_opt(i64::MAX).is_none());
+    /// ~~~~
+    #[inline]
+    pub fn from_timestamp_micros_opt(micros: i64) -> Option<NaiveDateTime> {
+        let (secs, micros) = div_mod_floor(micros, 1_000_000);
+        NaiveDateTime::from_timestamp_opt(secs, micros as u32 * 1_000)
+    }
+
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap nanoseconds
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Every `i64` value is in range (from 1677 to 2262 CE), so this never panics.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// let dt = NaiveDateTime::from_timestamp_nanos(1_000_000_000_555_666_777);
+    /// assert_eq!(dt, NaiveDate::from_ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555_666_777));
+    /// ~~~~
+    #[inline]
+    pub fn from_timestamp_nanos(nanos: i64) -> NaiveDateTime {
+        let (secs, nanos) = div_mod_floor(nanos, 1_000_000_000);
+        NaiveDateTime::from_timestamp(secs, nanos as u32)
+    }
+
+    /// Makes a new `NaiveDateTime` from the fractional Julian Date,
+    /// i.e. the number of days since the noon of January 1, 4713 BCE
+    /// in the proleptic Julian calendar.
+    ///
+    /// The conversion is subject to the precision of `f64`,
+    /// which is about tens of microseconds for the contemporary dates.
+    ///
+    /// Panics on the out-of-range date or non-finite numbers.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDateTime::from_julian_day(2_451_545.0),
+    ///            NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0));
+    /// assert_eq!(NaiveDateTime::from_julian_day(2_451_544.75),
+    ///            NaiveDate::from_ymd(2000, 1, 1).and_hms(6, 0, 0));
+    /// ~~~~
+    pub fn from_julian_day(jd: f64) -> NaiveDateTime {
+        NaiveDateTime::from_julian_day_opt(jd).expect("invalid or out-of-range datetime")
+    }
+
+    /// Makes a new `NaiveDateTime` from the fractional Julian Date,
+    /// i.e. the number of days since the noon of January 1, 4713 BCE
+    /// in the proleptic Julian calendar.
+    ///
+    /// Returns `None` on the out-of-range date or non-finite numbers.
+    pub fn from_julian_day_opt(jd: f64) -> Option<NaiveDateTime> {
+        NaiveDateTime::from_modified_julian_day_opt(jd - MODIFIED_JULIAN_DATE_0)
+    }
+
+    /// Makes a new `NaiveDateTime` from the fractional Modified Julian Date,
+    /// i.e. the number of days since the midnight of November 17, 1858.
+    ///
+    /// The conversion is subject to the precision of `f64`,
+    /// which is about a microsecond for the contemporary dates.
+    ///
+    /// Panics on the out-of-range date or non-finite numbers.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// assert_eq!(NaiveDateTime::from_modified_julian_day(51_544.5),
+    ///            NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0));
+    /// ~~~~
+    pub fn from_modified_julian_day(mjd: f64) -> NaiveDateTime {
+        NaiveDateTime::from_modified_julian_day_opt(mjd).expect("invalid or out-of-range datetime")
+    }
+
+    /// Makes a new `NaiveDateTime` from the fractional Modified Julian Date,
+    /// i.e. the number of days since the midnight of November 17, 1858.
+    ///
+    /// Returns `None` on the out-of-range date or non-finite numbers.
+    pub fn from_modified_julian_day_opt(mjd: f64) -> Option<NaiveDateTime> {
+        const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+
+        let days = mjd.floor();
+        let nanos = ((mjd - days) * NANOS_PER_DAY as f64).round();
+        let (mut days, mut nanos) = (try_opt!(days.to_i32()), try_opt!(nanos.to_i64()));
+        if nanos >= NANOS_PER_DAY { // rounded up to the next midnight
+            days = try_opt!(days.checked_add(1));
+            nanos -= NANOS_PER_DAY;
+        }
+        let date = try_opt!(NaiveDate::from_modified_julian_day_opt(days));
+        let (secs, nanos) = div_mod_floor(nanos, 1_000_000_000);
+        let time = try_opt!(NaiveTime::from_num_seconds_from_midnight_opt(secs as u32,
+                                                                          nanos as u32));
+        Some(NaiveDateTime { date: date, time: time })
+    }
+
+    /// Makes a new `NaiveDateTime` corresponding to a UTC date and time,
+    /// from the number of non-leap nanoseconds as `i128`
+    /// since the midnight UTC on January 1, 1970 (aka "UNIX timestamp").
+    ///
+    /// Returns `None` on the out-of-range number of nanoseconds.
+    /// This requires the `i128` feature.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # #[cfg(feature = "i128")] fn main() {
+    /// use chrono::{NaiveDateTime, NaiveDate};
+    ///
+    /// let dt = NaiveDateTime::from_timestamp_nanos_i128_opt(9_246_182_400_000_000_001);
+    /// assert_eq!(dt, Some(NaiveDate::from_ymd(2263, 1, 1).and_hms_nano(0, 0, 0, 1)));
+    /// assert_eq!(NaiveDateTime::from_timestamp_nanos_i128_opt(i128::max_value()), None);
+    /// # }
+    /// # #[cfg(not(feature = "i128"))] fn main() {}
+    /// ~~~~
+    #[cfg(feature = "i128")]
+    #[inline]
+    pub fn from_timestamp_nanos_i128_opt(nanos: i128) -> Option<NaiveDateTime> {
+        let (mut secs, mut nanos) = (nanos / 1_000_000_000, nanos % 1_000_000_000);
+        if nanos < 0 {
+            secs -= 1;
+            nanos += 1_000_000_000;
+        }
+        if secs < i64::min_value() as i128 || secs > i64::max_value() as i128 { return None; }
+        NaiveDateTime::from_timestamp_opt(secs as i64, nanos as u32)
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -240,6 +462,42 @@ impl NaiveDateTime {
         self.time
     }
 
+    /// Returns the fractional Julian Date,
+    /// i.e. the number of days since the noon of January 1, 4713 BCE
+    /// in the proleptic Julian calendar.
+    ///
+    /// The conversion is subject to the precision of `f64`,
+    /// which is about tens of microseconds for the contemporary dates.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2000, 1, 1).and_hms(18, 0, 0);
+    /// assert_eq!(dt.to_julian_day(), 2_451_545.25);
+    /// ~~~~
+    pub fn to_julian_day(&self) -> f64 {
+        self.to_modified_julian_day() + MODIFIED_JULIAN_DATE_0
+    }
+
+    /// Returns the fractional Modified Julian Date,
+    /// i.e. the number of days since the midnight of November 17, 1858.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2000, 1, 1).and_hms(18, 0, 0);
+    /// assert_eq!(dt.to_modified_julian_day(), 51_544.75);
+    /// ~~~~
+    pub fn to_modified_julian_day(&self) -> f64 {
+        let secs = self.time.num_seconds_from_midnight() as f64 +
+                   self.time.nanosecond() as f64 / 1e9;
+        self.date.to_modified_julian_day() as f64 + secs / 86_400.0
+    }
+
     /// Returns the number of non-leap seconds since the midnight on January 1, 1970.
     ///
     /// Note that this does *not* account for the timezone!
@@ -290,6 +548,104 @@ impl NaiveDateTime {
         as_ms + self.timestamp_subsec_millis() as i64
     }
 
+    /// Returns the number of non-leap *microseconds* since midnight on January 1, 1970.
+    ///
+    /// Note that this does *not* account for the timezone!
+    /// The true "UNIX timestamp" would count seconds since the midnight *UTC* on the epoch.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(1970, 1, 1).and_hms_micro(0, 0, 1, 444);
+    /// assert_eq!(dt.timestamp_micros(), 1_000_444);
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9).and_hms_micro(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_micros(), 1_000_000_000_000_555);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_micros(&self) -> i64 {
+        // this never overflows: the supported years need at most 63 bits in microseconds
+        let as_us = self.timestamp() * 1_000_000;
+        as_us + self.timestamp_subsec_micros() as i64
+    }
+
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970.
+    ///
+    /// Note that this does *not* account for the timezone!
+    /// The true "UNIX timestamp" would count seconds since the midnight *UTC* on the epoch.
+    ///
+    /// Panics if the value does not fit in `i64`,
+    /// which happens for dates before about 1677 CE or after about 2262 CE.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(1970, 1, 1).and_hms_nano(0, 0, 1, 444);
+    /// assert_eq!(dt.timestamp_nanos(), 1_000_000_444);
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_nanos(), 1_000_000_000_000_000_555);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_nanos(&self) -> i64 {
+        let as_ns = self.timestamp_nanos_opt();
+        as_ns.expect("value can not be represented in a timestamp with nanosecond precision")
+    }
+
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970.
+    ///
+    /// Returns `None` if the value does not fit in `i64`,
+    /// which happens for dates before about 1677 CE or after about 2262 CE.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// let dt = NaiveDate::from_ymd(2263, 1, 1).and_hms(0, 0, 0);
+    /// assert_eq!(dt.timestamp_nanos_opt(), None);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        let mut secs = self.timestamp();
+        let mut nanos = self.timestamp_subsec_nanos() as i64;
+        if secs < 0 && nanos > 0 {
+            // avoid the overflow of the intermediate value near the lower bound
+            secs += 1;
+            nanos -= 1_000_000_000;
+        }
+        secs.checked_mul(1_000_000_000).and_then(|ns| ns.checked_add(nanos))
+    }
+
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970,
+    /// as `i128` which never overflows.
+    ///
+    /// This requires the `i128` feature.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # #[cfg(feature = "i128")] fn main() {
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2263, 1, 1).and_hms_nano(0, 0, 0, 1);
+    /// assert_eq!(dt.timestamp_nanos_i128(), 9_246_182_400_000_000_001);
+    /// # }
+    /// # #[cfg(not(feature = "i128"))] fn main() {}
+    /// ~~~~
+    #[cfg(feature = "i128")]
+    #[inline]
+    pub fn timestamp_nanos_i128(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000_000 + self.timestamp_subsec_nanos() as i128
+    }
+
     /// Returns the number of milliseconds since the last whole non-leap second.
     ///
     /// The return value ranges from 0 to 999,
@@ -521,6 +877,111 @@ impl NaiveDateTime {
         Some(NaiveDateTime { date: date, time: time })
     }
 
+    /// Adds given number of months to the current date and time,
+    /// clamping the day to the last day of the resulting month if needed.
+    /// The time is unchanged.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Months};
+    ///
+    /// let dt = NaiveDate::from_ymd(2016, 1, 31).and_hms(3, 5, 7);
+    /// assert_eq!(dt.checked_add_months(Months(1)),
+    ///            Some(NaiveDate::from_ymd(2016, 2, 29).and_hms(3, 5, 7)));
+    /// ~~~~
+    pub fn checked_add_months(self, months: Months) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_add_months(months));
+        Some(NaiveDateTime { date: date, time: self.time })
+    }
+
+    /// Subtracts given number of months from the current date and time,
+    /// clamping the day to the last day of the resulting month if needed.
+    /// The time is unchanged.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_sub_months(self, months: Months) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_sub_months(months));
+        Some(NaiveDateTime { date: date, time: self.time })
+    }
+
+    /// Adds given number of days to the current date and time.
+    /// The time is unchanged.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_add_days(self, days: Days) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_add_days(days));
+        Some(NaiveDateTime { date: date, time: self.time })
+    }
+
+    /// Subtracts given number of days from the current date and time.
+    /// The time is unchanged.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_sub_days(self, days: Days) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_sub_days(days));
+        Some(NaiveDateTime { date: date, time: self.time })
+    }
+
+    /// Adds given `Duration` to the current date and time,
+    /// clamping the result to the representable range on overflow.
+    ///
+    /// The result is clamped to [`MIN_DATETIME`](./constant.MIN_DATETIME.html) or
+    /// [`MAX_DATETIME`](./constant.MAX_DATETIME.html).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::{MIN_DATETIME, MAX_DATETIME};
+    /// use time::Duration;
+    ///
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8).and_hms(3, 5, 7);
+    /// assert_eq!(dt.saturating_add_signed(Duration::seconds(1)),
+    ///            NaiveDate::from_ymd(2016, 7, 8).and_hms(3, 5, 8));
+    /// assert_eq!(dt.saturating_add_signed(Duration::days(1_000_000_000)), MAX_DATETIME);
+    /// assert_eq!(dt.saturating_add_signed(Duration::days(-1_000_000_000)), MIN_DATETIME);
+    /// # }
+    /// ~~~~
+    pub fn saturating_add_signed(self, rhs: OldDuration) -> NaiveDateTime {
+        match self.checked_add_signed(rhs) {
+            Some(dt) => dt,
+            None if rhs < OldDuration::zero() => MIN_DATETIME,
+            None => MAX_DATETIME,
+        }
+    }
+
+    /// Subtracts given `Duration` from the current date and time,
+    /// clamping the result to the representable range on overflow.
+    ///
+    /// See [`saturating_add_signed`](#method.saturating_add_signed) for the clamped values.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::{MIN_DATETIME, MAX_DATETIME};
+    /// use time::Duration;
+    ///
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8).and_hms(3, 5, 7);
+    /// assert_eq!(dt.saturating_sub_signed(Duration::seconds(1)),
+    ///            NaiveDate::from_ymd(2016, 7, 8).and_hms(3, 5, 6));
+    /// assert_eq!(dt.saturating_sub_signed(Duration::days(1_000_000_000)), MIN_DATETIME);
+    /// assert_eq!(dt.saturating_sub_signed(Duration::days(-1_000_000_000)), MAX_DATETIME);
+    /// # }
+    /// ~~~~
+    pub fn saturating_sub_signed(self, rhs: OldDuration) -> NaiveDateTime {
+        match self.checked_sub_signed(rhs) {
+            Some(dt) => dt,
+            None if rhs > OldDuration::zero() => MIN_DATETIME,
+            None => MAX_DATETIME,
+        }
+    }
+
     /// Subtracts another `NaiveDateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     ///
@@ -638,6 +1099,36 @@ impl NaiveDateTime {
     pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
         self.format_with_items(StrftimeItems::new(fmt))
     }
+
+    /// Formats the combined date and time with the specified formatting items,
+    /// printing the month and weekday names in given locale.
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized_with_items<'a, I>(&self, items: I, locale: Locale) -> DelayedFormat<I>
+            where I: Iterator<Item=Item<'a>> + Clone {
+        DelayedFormat::new_with_locale(Some(self.date), Some(self.time), items, locale)
+    }
+
+    /// Formats the combined date and time with the specified format string,
+    /// printing the month and weekday names in given locale.
+    /// See [`format`](#method.format) for other details.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::format::Locale;
+    ///
+    /// let dt = NaiveDate::from_ymd(2015, 9, 5).and_hms(23, 56, 4);
+    /// assert_eq!(dt.format_localized("%a %-d %b %Y %H:%M", Locale::Spanish).to_string(),
+    ///            "sáb 5 sep 2015 23:56");
+    /// ~~~~
+    #[cfg(feature = "locales")]
+    #[inline]
+    pub fn format_localized<'a>(&self, fmt: &'a str,
+                                locale: Locale) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_localized_with_items(StrftimeItems::new(fmt), locale)
+    }
 }
 
 impl Datelike for NaiveDateTime {
@@ -1279,6 +1770,42 @@ impl SubAssign<OldDuration> for NaiveDateTime {
     }
 }
 
+impl Add<Months> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn add(self, rhs: Months) -> NaiveDateTime {
+        self.checked_add_months(rhs).expect("`NaiveDateTime + Months` out of range")
+    }
+}
+
+impl Sub<Months> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> NaiveDateTime {
+        self.checked_sub_months(rhs).expect("`NaiveDateTime - Months` out of range")
+    }
+}
+
+impl Add<Days> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn add(self, rhs: Days) -> NaiveDateTime {
+        self.checked_add_days(rhs).expect("`NaiveDateTime + Days` out of range")
+    }
+}
+
+impl Sub<Days> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> NaiveDateTime {
+        self.checked_sub_days(rhs).expect("`NaiveDateTime - Days` out of range")
+    }
+}
+
 /// The `Debug` output of the naive date and time `dt` is same to
 /// [`dt.format("%Y-%m-%dT%H:%M:%S%.f")`](../format/strftime/index.html).
 ///
@@ -1746,6 +2273,301 @@ pub mod serde {
 
     }
 
+    /// Used to serialize/deserialize from millisecond-precision timestamps
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde_json;
+    /// # extern crate chrono;
+    /// # use chrono::{NaiveDate, NaiveDateTime};
+    /// use chrono::naive::serde::ts_milliseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "ts_milliseconds")]
+    ///     time: NaiveDateTime
+    /// }
+    ///
+    /// # fn example() -> Result<S, serde_json::Error> {
+    /// let time = NaiveDate::from_ymd(2015, 5, 15).and_hms_milli(10, 0, 0, 123);
+    /// let my_s = S { time: time };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1431684000123}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
+    /// # Ok(my_s)
+    /// # }
+    /// # fn main() { example().unwrap(); }
+    /// ```
+    pub mod ts_milliseconds {
+        use std::fmt;
+        use serdelib::{ser, de};
+
+        use NaiveDateTime;
+
+        /// Deserialize a `NaiveDateTime` from a milliseconds timestamp
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDateTime, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            d.deserialize_i64(NaiveDateTimeFromMillisecondsVisitor)
+        }
+
+        /// Serialize a `NaiveDateTime` into an integer number of milliseconds since the epoch
+        pub fn serialize<S>(dt: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.serialize_i64(dt.timestamp_millis())
+        }
+
+        struct NaiveDateTimeFromMillisecondsVisitor;
+
+        impl<'de> de::Visitor<'de> for NaiveDateTimeFromMillisecondsVisitor {
+            type Value = NaiveDateTime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(formatter, "a unix timestamp in milliseconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<NaiveDateTime, E>
+                where E: de::Error
+            {
+                NaiveDateTime::from_timestamp_millis_opt(value)
+                    .ok_or_else(|| E::custom(format!("value is not a legal timestamp: {}", value)))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<NaiveDateTime, E>
+                where E: de::Error
+            {
+                if value > i64::max_value() as u64 {
+                    return Err(E::custom(format!("value is not a legal timestamp: {}", value)));
+                }
+                self.visit_i64(value as i64)
+            }
+        }
+    }
+
+    /// Used to serialize/deserialize from nanosecond-precision timestamps
+    ///
+    /// The timestamps in nanoseconds only cover the years from 1677 to 2262,
+    /// and serializing the `NaiveDateTime` outside of that range is an error.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde_json;
+    /// # extern crate chrono;
+    /// # use chrono::{NaiveDate, NaiveDateTime};
+    /// use chrono::naive::serde::ts_nanoseconds;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "ts_nanoseconds")]
+    ///     time: NaiveDateTime
+    /// }
+    ///
+    /// # fn example() -> Result<S, serde_json::Error> {
+    /// let time = NaiveDate::from_ymd(2015, 5, 15).and_hms_nano(10, 0, 0, 123_456_789);
+    /// let my_s = S { time: time };
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":1431684000123456789}"#);
+    /// let my_s: S = serde_json::from_str(&as_string)?;
+    /// assert_eq!(my_s.time, time);
+    /// # Ok(my_s)
+    /// # }
+    /// # fn main() { example().unwrap(); }
+    /// ```
+    pub mod ts_nanoseconds {
+        use std::fmt;
+        use serdelib::{ser, de};
+
+        use NaiveDateTime;
+        use div::div_mod_floor;
+
+        /// Deserialize a `NaiveDateTime` from a nanoseconds timestamp
+        pub fn deserialize<'de, D>(d: D) -> Result<NaiveDateTime, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            d.deserialize_i64(NaiveDateTimeFromNanosecondsVisitor)
+        }
+
+        /// Serialize a `NaiveDateTime` into an integer number of nanoseconds since the epoch
+        pub fn serialize<S>(dt: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            match dt.timestamp_nanos_opt() {
+                Some(nanos) => serializer.serialize_i64(nanos),
+                None => Err(ser::Error::custom(format!("value is out of range for a timestamp \
+                                                        in nanoseconds: {:?}", dt))),
+            }
+        }
+
+        struct NaiveDateTimeFromNanosecondsVisitor;
+
+        impl<'de> de::Visitor<'de> for NaiveDateTimeFromNanosecondsVisitor {
+            type Value = NaiveDateTime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(formatter, "a unix timestamp in nanoseconds")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<NaiveDateTime, E>
+                where E: de::Error
+            {
+                let (secs, nanos) = div_mod_floor(value, 1_000_000_000);
+                NaiveDateTime::from_timestamp_opt(secs, nanos as u32)
+                    .ok_or_else(|| E::custom(format!("value is not a legal timestamp: {}", value)))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<NaiveDateTime, E>
+                where E: de::Error
+            {
+                if value > i64::max_value() as u64 {
+                    return Err(E::custom(format!("value is not a legal timestamp: {}", value)));
+                }
+                self.visit_i64(value as i64)
+            }
+        }
+    }
+
+    /// Used to serialize/deserialize from strings in a custom `strftime`-like format
+    ///
+    /// The functions here take the format string as an extra argument,
+    /// so they should be wrapped in a module fixing the format
+    /// before being used with `serde`'s `with` attribute.
+    /// See [`chrono::serde::custom`](../../serde/custom/index.html) for an example.
+    pub mod custom {
+        use std::fmt;
+        use serdelib::{ser, de};
+
+        use NaiveDateTime;
+
+        /// Deserialize a `NaiveDateTime` from a string in given format
+        pub fn deserialize<'de, D>(d: D, fmt: &str) -> Result<NaiveDateTime, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            d.deserialize_str(CustomVisitor { fmt: fmt })
+        }
+
+        /// Serialize a `NaiveDateTime` into a string in given format
+        pub fn serialize<S>(dt: &NaiveDateTime, fmt: &str,
+                            serializer: S) -> Result<S::Ok, S::Error>
+            where S: ser::Serializer
+        {
+            serializer.collect_str(&dt.format(fmt))
+        }
+
+        struct CustomVisitor<'a> {
+            fmt: &'a str,
+        }
+
+        impl<'a, 'de> de::Visitor<'de> for CustomVisitor<'a> {
+            type Value = NaiveDateTime;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+            {
+                write!(formatter, "a date and time string in the format {:?}", self.fmt)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<NaiveDateTime, E>
+                where E: de::Error
+            {
+                NaiveDateTime::parse_from_str(value, self.fmt)
+                    .map_err(|err| E::custom(format!("{}", err)))
+            }
+        }
+    }
+
+    /// Defines a module to serialize/deserialize `Option<NaiveDateTime>`
+    /// with given module for `NaiveDateTime`.
+    macro_rules! option_module {
+        ($name:ident, $inner:ident, $what:expr, $doc:expr) => (
+            #[doc = $doc]
+            #[doc = ""]
+            #[doc = "`None` is serialized as the unit value (`null` in JSON)."]
+            pub mod $name {
+                use std::fmt;
+                use serdelib::{ser, de};
+
+                use NaiveDateTime;
+
+                /// Deserialize an `Option<NaiveDateTime>`
+                pub fn deserialize<'de, D>(d: D) -> Result<Option<NaiveDateTime>, D::Error>
+                    where D: de::Deserializer<'de>
+                {
+                    d.deserialize_option(OptionVisitor)
+                }
+
+                /// Serialize an `Option<NaiveDateTime>`
+                pub fn serialize<S>(opt: &Option<NaiveDateTime>,
+                                    serializer: S) -> Result<S::Ok, S::Error>
+                    where S: ser::Serializer
+                {
+                    struct Wrapped<'a>(&'a NaiveDateTime);
+
+                    impl<'a> ser::Serialize for Wrapped<'a> {
+                        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                            where S: ser::Serializer
+                        {
+                            super::$inner::serialize(self.0, serializer)
+                        }
+                    }
+
+                    match *opt {
+                        Some(ref dt) => serializer.serialize_some(&Wrapped(dt)),
+                        None => serializer.serialize_none(),
+                    }
+                }
+
+                struct OptionVisitor;
+
+                impl<'de> de::Visitor<'de> for OptionVisitor {
+                    type Value = Option<NaiveDateTime>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+                    {
+                        write!(formatter, "{} or none", $what)
+                    }
+
+                    fn visit_some<D>(self, d: D) -> Result<Option<NaiveDateTime>, D::Error>
+                        where D: de::Deserializer<'de>
+                    {
+                        super::$inner::deserialize(d).map(Some)
+                    }
+
+                    fn visit_none<E>(self) -> Result<Option<NaiveDateTime>, E>
+                        where E: de::Error
+                    {
+                        Ok(None)
+                    }
+
+                    fn visit_unit<E>(self) -> Result<Option<NaiveDateTime>, E>
+                        where E: de::Error
+                    {
+                        Ok(None)
+                    }
+                }
+            }
+        )
+    }
+
+    option_module!(ts_seconds_option, ts_seconds, "a unix timestamp in seconds",
+                   "Used to serialize/deserialize `Option<NaiveDateTime>` from a unix timestamp \
+                    in seconds, as [`ts_seconds`](./ts_seconds/index.html) \
+                    does for `NaiveDateTime`.");
+    option_module!(ts_milliseconds_option, ts_milliseconds, "a unix timestamp in milliseconds",
+                   "Used to serialize/deserialize `Option<NaiveDateTime>` from a unix timestamp \
+                    in milliseconds, as [`ts_milliseconds`](./ts_milliseconds/index.html) \
+                    does for `NaiveDateTime`.");
+    option_module!(ts_nanoseconds_option, ts_nanoseconds, "a unix timestamp in nanoseconds",
+                   "Used to serialize/deserialize `Option<NaiveDateTime>` from a unix timestamp \
+                    in nanoseconds, as [`ts_nanoseconds`](./ts_nanoseconds/index.html) \
+                    does for `NaiveDateTime`.");
+
     #[cfg(test)] extern crate serde_json;
     #[cfg(test)] extern crate bincode;
 
@@ -1773,10 +2595,31 @@ pub mod serde {
     }
 }
 
+#[cfg(feature = "quickcheck")]
+mod arbitrary {
+    use super::NaiveDateTime;
+    use quickcheck::{Arbitrary, Gen};
+    use naive::{NaiveDate, NaiveTime};
+
+    impl Arbitrary for NaiveDateTime {
+        fn arbitrary(g: &mut Gen) -> NaiveDateTime {
+            NaiveDateTime::new(NaiveDate::arbitrary(g), NaiveTime::arbitrary(g))
+        }
+    }
+
+    #[cfg(test)]
+    quickcheck! {
+        fn prop_string_roundtrip(dt: NaiveDateTime) -> bool {
+            format!("{:?}", dt).parse::<NaiveDateTime>() == Ok(dt)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::NaiveDateTime;
+    use super::{NaiveDateTime, MIN_DATETIME, MAX_DATETIME};
     use Datelike;
+    use div::div_floor;
     use naive::{NaiveDate, MIN_DATE, MAX_DATE};
     use std::i64;
     use oldtime::Duration;
@@ -1794,6 +2637,56 @@ mod tests {
         assert_eq!(from_timestamp(i64::MAX), None);
     }
 
+    #[test]
+    fn test_datetime_min_max() {
+        assert_eq!(MIN_DATETIME, MIN_DATE.and_hms(0, 0, 0));
+        assert_eq!(MAX_DATETIME, MAX_DATE.and_hms_nano(23, 59, 59, 999_999_999));
+        assert_eq!(MIN_DATETIME.checked_sub_signed(Duration::nanoseconds(1)), None);
+        assert_eq!(MAX_DATETIME.checked_add_signed(Duration::nanoseconds(1)), None);
+    }
+
+    #[test]
+    fn test_datetime_from_timestamp_subsec_units() {
+        let ymdhmsn = |y,m,d,h,n,s,nano| NaiveDate::from_ymd(y,m,d).and_hms_nano(h,n,s,nano);
+        let epoch = ymdhmsn(1970, 1, 1, 0, 0, 0, 0);
+        let before_epoch = ymdhmsn(1969, 12, 31, 23, 59, 59, 999_999_999);
+
+        assert_eq!(NaiveDateTime::from_timestamp_millis_opt(0), Some(epoch));
+        assert_eq!(NaiveDateTime::from_timestamp_millis_opt(-1),
+                   Some(ymdhmsn(1969, 12, 31, 23, 59, 59, 999_000_000)));
+        assert_eq!(NaiveDateTime::from_timestamp_millis_opt(i64::MIN), None);
+        assert_eq!(NaiveDateTime::from_timestamp_millis_opt(i64::MAX), None);
+
+        assert_eq!(NaiveDateTime::from_timestamp_micros_opt(0), Some(epoch));
+        assert_eq!(NaiveDateTime::from_timestamp_micros_opt(-1),
+                   Some(ymdhmsn(1969, 12, 31, 23, 59, 59, 999_999_000)));
+        assert_eq!(NaiveDateTime::from_timestamp_micros_opt(i64::MIN), None);
+        assert_eq!(NaiveDateTime::from_timestamp_micros_opt(i64::MAX), None);
+
+        assert_eq!(NaiveDateTime::from_timestamp_nanos(0), epoch);
+        assert_eq!(NaiveDateTime::from_timestamp_nanos(-1), before_epoch);
+        assert_eq!(NaiveDateTime::from_timestamp_nanos(i64::MIN),
+                   ymdhmsn(1677, 9, 21, 0, 12, 43, 145_224_192));
+        assert_eq!(NaiveDateTime::from_timestamp_nanos(i64::MAX),
+                   ymdhmsn(2262, 4, 11, 23, 47, 16, 854_775_807));
+
+        // round trips
+        for &nanos in [i64::MIN, -1_000_000_001, -1, 0, 1, 1_000_000_001, i64::MAX].iter() {
+            let dt = NaiveDateTime::from_timestamp_nanos(nanos);
+            assert_eq!(dt.timestamp_nanos(), nanos);
+            assert_eq!(NaiveDateTime::from_timestamp_micros(dt.timestamp_micros()),
+                       NaiveDateTime::from_timestamp_micros(div_floor(nanos, 1_000)));
+            assert_eq!(NaiveDateTime::from_timestamp_millis(dt.timestamp_millis()),
+                       NaiveDateTime::from_timestamp_millis(div_floor(nanos, 1_000_000)));
+        }
+        assert_eq!(before_epoch.timestamp_micros(), -1);
+        assert_eq!(before_epoch.timestamp_millis(), -1);
+        assert_eq!(MAX_DATE.and_hms_micro(23, 59, 59, 999_999).timestamp_micros(),
+                   8_210_298_412_799_999_999);
+        assert_eq!(NaiveDateTime::from_timestamp_micros(8_210_298_412_799_999_999),
+                   MAX_DATE.and_hms_micro(23, 59, 59, 999_999));
+    }
+
     #[test]
     fn test_datetime_add() {
         fn check((y,m,d,h,n,s): (i32,u32,u32,u32,u32,u32), rhs: Duration,
@@ -1863,6 +2756,39 @@ mod tests {
         assert_eq!(date,  ymdhms(1997, 9, 16, 23, 30, 10));
     }
 
+    #[test]
+    fn test_datetime_julian_day() {
+        let ymdhms = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s);
+        for &(dt, jd) in &[(ymdhms(2000, 1, 1, 12, 0, 0), 2_451_545.0),
+                           (ymdhms(2000, 1, 1, 0, 0, 0), 2_451_544.5),
+                           (ymdhms(1858, 11, 17, 0, 0, 0), 2_400_000.5),
+                           (ymdhms(-4713, 11, 24, 12, 0, 0), 0.0),
+                           (ymdhms(-4713, 11, 24, 6, 0, 0), -0.25)] {
+            assert_eq!(dt.to_julian_day(), jd);
+            assert_eq!(dt.to_modified_julian_day(), jd - 2_400_000.5);
+            assert_eq!(NaiveDateTime::from_julian_day_opt(jd), Some(dt));
+            assert_eq!(NaiveDateTime::from_modified_julian_day_opt(jd - 2_400_000.5), Some(dt));
+        }
+
+        // round trips are precise to tens of microseconds
+        let dt = NaiveDate::from_ymd(2017, 8, 19).and_hms_micro(13, 45, 27, 123_456);
+        let diff = NaiveDateTime::from_julian_day(dt.to_julian_day()).signed_duration_since(dt);
+        assert!(diff.num_microseconds().unwrap().abs() < 100);
+        let diff = NaiveDateTime::from_modified_julian_day(dt.to_modified_julian_day())
+                       .signed_duration_since(dt);
+        assert!(diff.num_microseconds().unwrap().abs() < 10);
+
+        // rounded up to the next midnight
+        assert_eq!(NaiveDateTime::from_modified_julian_day_opt(-1e-16),
+                   Some(ymdhms(1858, 11, 17, 0, 0, 0)));
+        assert_eq!(NaiveDateTime::from_modified_julian_day_opt(0.999_999_999_999_999_9),
+                   Some(ymdhms(1858, 11, 18, 0, 0, 0)));
+
+        assert_eq!(NaiveDateTime::from_julian_day_opt(1e100), None);
+        assert_eq!(NaiveDateTime::from_julian_day_opt(::std::f64::NAN), None);
+        assert_eq!(NaiveDateTime::from_julian_day_opt(::std::f64::INFINITY), None);
+    }
+
     #[test]
     fn test_datetime_timestamp() {
         let to_timestamp = |y,m,d,h,n,s| NaiveDate::from_ymd(y,m,d).and_hms(h,n,s).timestamp();
@@ -1,10 +1,15 @@
 // This is a part of Chrono.
 // See README.md and LICENSE.txt for details.
 
-//! ISO 8601 week.
+//! ISO 8601 week and week date.
 
-use std::fmt;
+use std::{str, fmt};
+use std::ops::{Add, Sub};
+use oldtime::Duration as OldDuration;
 
+use {Weekday, Datelike};
+use format::{Item, Numeric, Pad, Parsed, ParseError, ParseResult, parse};
+use super::NaiveDate;
 use super::internals::{DateImpl, Of, YearFlags};
 
 /// ISO 8601 week.
@@ -138,10 +143,215 @@ impl fmt::Debug for IsoWeek {
     }
 }
 
+/// ISO 8601 week date, i.e. a combination of the ISO year, the ISO week number and the weekday.
+///
+/// This is an alternative representation of [`NaiveDate`](./struct.NaiveDate.html)
+/// for calendars organized around ISO weeks, and converts from and to `NaiveDate` losslessly.
+/// Its `Display` and `FromStr` implementations use the format like `2019-W03-5`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, IsoWeekDate, Weekday};
+///
+/// let wd = IsoWeekDate::from_ywd(2019, 3, Weekday::Fri);
+/// assert_eq!(wd.to_string(), "2019-W03-5");
+/// assert_eq!(wd.to_naive_date(), NaiveDate::from_ymd(2019, 1, 18));
+/// assert_eq!("2019-W03-5".parse::<IsoWeekDate>(), Ok(wd));
+/// assert_eq!(IsoWeekDate::from(NaiveDate::from_ymd(2019, 1, 18)), wd);
+/// ~~~~
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct IsoWeekDate {
+    date: NaiveDate,
+}
+
+impl IsoWeekDate {
+    /// Makes a new `IsoWeekDate` from the ISO year, the ISO week number (starting from 1)
+    /// and the weekday.
+    ///
+    /// Panics on the out-of-range date and/or invalid week number.
+    pub fn from_ywd(year: i32, week: u32, weekday: Weekday) -> IsoWeekDate {
+        IsoWeekDate::from_ywd_opt(year, week, weekday).expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `IsoWeekDate` from the ISO year, the ISO week number (starting from 1)
+    /// and the weekday.
+    ///
+    /// Returns `None` on the out-of-range date and/or invalid week number.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{IsoWeekDate, Weekday};
+    ///
+    /// assert!(IsoWeekDate::from_ywd_opt(2015, 53, Weekday::Sun).is_some());
+    /// assert!(IsoWeekDate::from_ywd_opt(2016, 53, Weekday::Sun).is_none());
+    /// assert!(IsoWeekDate::from_ywd_opt(2016, 0, Weekday::Sun).is_none());
+    /// ~~~~
+    pub fn from_ywd_opt(year: i32, week: u32, weekday: Weekday) -> Option<IsoWeekDate> {
+        NaiveDate::from_isoywd_opt(year, week, weekday).map(|date| IsoWeekDate { date: date })
+    }
+
+    /// Returns the ISO year number, which might not match the calendar year number.
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.date.iso_week().year()
+    }
+
+    /// Returns the ISO week number starting from 1.
+    #[inline]
+    pub fn week(&self) -> u32 {
+        self.date.iso_week().week()
+    }
+
+    /// Returns the day of week.
+    #[inline]
+    pub fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    /// Returns the ISO week this date belongs to.
+    #[inline]
+    pub fn iso_week(&self) -> IsoWeek {
+        self.date.iso_week()
+    }
+
+    /// Converts this week date to the corresponding calendar date.
+    #[inline]
+    pub fn to_naive_date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Adds given number of weeks, keeping the weekday.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{IsoWeekDate, Weekday};
+    ///
+    /// let wd = IsoWeekDate::from_ywd(2015, 52, Weekday::Wed);
+    /// assert_eq!(wd.checked_add_weeks(1), Some(IsoWeekDate::from_ywd(2015, 53, Weekday::Wed)));
+    /// assert_eq!(wd.checked_add_weeks(2), Some(IsoWeekDate::from_ywd(2016, 1, Weekday::Wed)));
+    /// assert_eq!(wd.checked_add_weeks(-52), Some(IsoWeekDate::from_ywd(2014, 52, Weekday::Wed)));
+    /// ~~~~
+    pub fn checked_add_weeks(self, weeks: i32) -> Option<IsoWeekDate> {
+        let date = try_opt!(self.date.checked_add_signed(OldDuration::weeks(weeks as i64)));
+        Some(IsoWeekDate { date: date })
+    }
+
+    /// Subtracts given number of weeks, keeping the weekday.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_sub_weeks(self, weeks: i32) -> Option<IsoWeekDate> {
+        let date = try_opt!(self.date.checked_sub_signed(OldDuration::weeks(weeks as i64)));
+        Some(IsoWeekDate { date: date })
+    }
+}
+
+impl From<NaiveDate> for IsoWeekDate {
+    fn from(date: NaiveDate) -> IsoWeekDate {
+        IsoWeekDate { date: date }
+    }
+}
+
+impl From<IsoWeekDate> for NaiveDate {
+    fn from(wd: IsoWeekDate) -> NaiveDate {
+        wd.date
+    }
+}
+
+/// An addition of `Duration` to `IsoWeekDate` discards the fractional days,
+/// in the same way as [`NaiveDate`](./struct.NaiveDate.html#impl-Add%3CDuration%3E).
+///
+/// Panics on underflow or overflow.
+impl Add<OldDuration> for IsoWeekDate {
+    type Output = IsoWeekDate;
+
+    #[inline]
+    fn add(self, rhs: OldDuration) -> IsoWeekDate {
+        IsoWeekDate { date: self.date + rhs }
+    }
+}
+
+/// A subtraction of `Duration` from `IsoWeekDate` discards the fractional days,
+/// in the same way as [`NaiveDate`](./struct.NaiveDate.html#impl-Sub%3CDuration%3E).
+///
+/// Panics on underflow or overflow.
+impl Sub<OldDuration> for IsoWeekDate {
+    type Output = IsoWeekDate;
+
+    #[inline]
+    fn sub(self, rhs: OldDuration) -> IsoWeekDate {
+        IsoWeekDate { date: self.date - rhs }
+    }
+}
+
+/// The `Debug` output of the ISO week date `wd` is same to
+/// [`wd.to_naive_date().format("%G-W%V-%u")`](../format/strftime/index.html),
+/// with an explicit sign for years before 1 BCE or after 9999 CE as in
+/// [`IsoWeek`](./struct.IsoWeek.html#impl-Debug).
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{IsoWeekDate, Weekday};
+///
+/// assert_eq!(format!("{:?}", IsoWeekDate::from_ywd(2015, 36, Weekday::Sat)), "2015-W36-6");
+/// assert_eq!(format!("{:?}", IsoWeekDate::from_ywd(-1, 52, Weekday::Sun)), "-0001-W52-7");
+/// ~~~~
+impl fmt::Debug for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}-{}", self.iso_week(), self.weekday().number_from_monday())
+    }
+}
+
+/// The `Display` output of the ISO week date `wd` is same to its `Debug` output.
+impl fmt::Display for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
+
+/// Parsing a `str` into an `IsoWeekDate` uses the format like `2019-W03-5`,
+/// which is the inverse of its `Display` output.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{IsoWeekDate, Weekday};
+///
+/// assert_eq!("2015-W53-7".parse::<IsoWeekDate>(),
+///            Ok(IsoWeekDate::from_ywd(2015, 53, Weekday::Sun)));
+/// assert_eq!("-0001-W52-7".parse::<IsoWeekDate>(),
+///            Ok(IsoWeekDate::from_ywd(-1, 52, Weekday::Sun)));
+/// assert!("2016-W53-7".parse::<IsoWeekDate>().is_err());
+/// assert!("2015-53-7".parse::<IsoWeekDate>().is_err());
+/// ~~~~
+impl str::FromStr for IsoWeekDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<IsoWeekDate> {
+        const ITEMS: &'static [Item<'static>] = &[
+            Item::Space(""), Item::Numeric(Numeric::IsoYear, Pad::Zero),
+            Item::Space(""), Item::Literal("-W"),
+            Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+            Item::Space(""), Item::Literal("-"),
+            Item::Space(""), Item::Numeric(Numeric::WeekdayFromMon, Pad::Zero),
+            Item::Space(""),
+        ];
+
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, s, ITEMS.iter().cloned()));
+        parsed.to_naive_date().map(IsoWeekDate::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use naive::{internals, MIN_DATE, MAX_DATE};
-    use Datelike;
+    use naive::{internals, NaiveDate, MIN_DATE, MAX_DATE};
+    use {Datelike, Weekday};
+    use oldtime::Duration;
+    use super::IsoWeekDate;
 
     #[test]
     fn test_iso_week_extremes() {
@@ -158,4 +368,35 @@ mod tests {
         assert_eq!(maxweek.week0(), 0);
         assert_eq!(format!("{:?}", maxweek), MAX_DATE.format("%G-W%V").to_string());
     }
+
+    #[test]
+    fn test_iso_week_date() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        for &(date, (y, w, wd)) in &[(ymd(2019, 1, 18), (2019, 3, Weekday::Fri)),
+                                     (ymd(2014, 12, 29), (2015, 1, Weekday::Mon)),
+                                     (ymd(2016, 1, 3), (2015, 53, Weekday::Sun)),
+                                     (MIN_DATE, (internals::MIN_YEAR, 1, MIN_DATE.weekday())),
+                                     (MAX_DATE, (internals::MAX_YEAR + 1, 1, MAX_DATE.weekday()))] {
+            let iwd = IsoWeekDate::from(date);
+            assert_eq!((iwd.year(), iwd.week(), iwd.weekday()), (y, w, wd));
+            assert_eq!(iwd.iso_week(), date.iso_week());
+            assert_eq!(NaiveDate::from(iwd), date);
+            assert_eq!(iwd.to_string().parse::<IsoWeekDate>(), Ok(iwd));
+        }
+
+        assert_eq!(IsoWeekDate::from_ywd_opt(2015, 54, Weekday::Mon), None);
+        assert_eq!(IsoWeekDate::from_ywd_opt(internals::MAX_YEAR + 1, 2, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_iso_week_date_add() {
+        let wd = IsoWeekDate::from_ywd(2015, 52, Weekday::Thu);
+        assert_eq!(wd.checked_add_weeks(1), Some(IsoWeekDate::from_ywd(2015, 53, Weekday::Thu)));
+        assert_eq!(wd.checked_add_weeks(2), Some(IsoWeekDate::from_ywd(2016, 1, Weekday::Thu)));
+        assert_eq!(wd.checked_sub_weeks(51), Some(IsoWeekDate::from_ywd(2015, 1, Weekday::Thu)));
+        assert_eq!(wd + Duration::days(4), IsoWeekDate::from_ywd(2015, 53, Weekday::Mon));
+        assert_eq!(wd - Duration::days(3), IsoWeekDate::from_ywd(2015, 52, Weekday::Mon));
+        assert_eq!(IsoWeekDate::from(MAX_DATE).checked_add_weeks(1), None);
+        assert_eq!(IsoWeekDate::from(MIN_DATE).checked_sub_weeks(1), None);
+    }
 }
@@ -0,0 +1,255 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! ISO 8601 ordinal date.
+
+use std::{str, fmt};
+use std::ops::{Add, Sub};
+use oldtime::Duration as OldDuration;
+
+use Datelike;
+use format::{Item, Numeric, Pad, Parsed, ParseError, ParseResult, parse};
+use super::NaiveDate;
+
+/// ISO 8601 ordinal date, i.e. a combination of the year and the day of the year.
+///
+/// This is an alternative representation of [`NaiveDate`](./struct.NaiveDate.html)
+/// for the users counting days in a year (e.g. in aviation or astronomy),
+/// and converts from and to `NaiveDate` losslessly.
+/// Its `Display` and `FromStr` implementations use the format like `2023-074`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, OrdinalDate};
+///
+/// let od = OrdinalDate::from_yo(2023, 74);
+/// assert_eq!(od.to_string(), "2023-074");
+/// assert_eq!(od.to_naive_date(), NaiveDate::from_ymd(2023, 3, 15));
+/// assert_eq!("2023-074".parse::<OrdinalDate>(), Ok(od));
+/// assert_eq!(OrdinalDate::from(NaiveDate::from_ymd(2023, 3, 15)), od);
+/// ~~~~
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct OrdinalDate {
+    date: NaiveDate,
+}
+
+impl OrdinalDate {
+    /// Makes a new `OrdinalDate` from the year and the day of year (starting from 1).
+    ///
+    /// Panics on the out-of-range date and/or invalid day of year.
+    pub fn from_yo(year: i32, ordinal: u32) -> OrdinalDate {
+        OrdinalDate::from_yo_opt(year, ordinal).expect("invalid or out-of-range date")
+    }
+
+    /// Makes a new `OrdinalDate` from the year and the day of year (starting from 1).
+    ///
+    /// Returns `None` on the out-of-range date and/or invalid day of year.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::OrdinalDate;
+    ///
+    /// assert!(OrdinalDate::from_yo_opt(2016, 366).is_some());
+    /// assert!(OrdinalDate::from_yo_opt(2015, 366).is_none());
+    /// assert!(OrdinalDate::from_yo_opt(2015, 0).is_none());
+    /// ~~~~
+    pub fn from_yo_opt(year: i32, ordinal: u32) -> Option<OrdinalDate> {
+        NaiveDate::from_yo_opt(year, ordinal).map(|date| OrdinalDate { date: date })
+    }
+
+    /// Returns the year number.
+    #[inline]
+    pub fn year(&self) -> i32 {
+        self.date.year()
+    }
+
+    /// Returns the day of year starting from 1.
+    ///
+    /// The return value ranges from 1 to 366. (The last day of year differs by years.)
+    #[inline]
+    pub fn ordinal(&self) -> u32 {
+        self.date.ordinal()
+    }
+
+    /// Returns the day of year starting from 0.
+    ///
+    /// The return value ranges from 0 to 365. (The last day of year differs by years.)
+    #[inline]
+    pub fn ordinal0(&self) -> u32 {
+        self.date.ordinal0()
+    }
+
+    /// Converts this ordinal date to the corresponding calendar date.
+    #[inline]
+    pub fn to_naive_date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Adds given number of days, carrying over to the next years if needed.
+    ///
+    /// Returns `None` when it will result in overflow.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::OrdinalDate;
+    ///
+    /// let od = OrdinalDate::from_yo(2015, 360);
+    /// assert_eq!(od.checked_add_days(5), Some(OrdinalDate::from_yo(2015, 365)));
+    /// assert_eq!(od.checked_add_days(6), Some(OrdinalDate::from_yo(2016, 1)));
+    /// assert_eq!(od.checked_add_days(-359), Some(OrdinalDate::from_yo(2015, 1)));
+    /// ~~~~
+    pub fn checked_add_days(self, days: i32) -> Option<OrdinalDate> {
+        let date = try_opt!(self.date.checked_add_signed(OldDuration::days(days as i64)));
+        Some(OrdinalDate { date: date })
+    }
+
+    /// Subtracts given number of days, carrying over to the previous years if needed.
+    ///
+    /// Returns `None` when it will result in overflow.
+    pub fn checked_sub_days(self, days: i32) -> Option<OrdinalDate> {
+        let date = try_opt!(self.date.checked_sub_signed(OldDuration::days(days as i64)));
+        Some(OrdinalDate { date: date })
+    }
+}
+
+impl From<NaiveDate> for OrdinalDate {
+    fn from(date: NaiveDate) -> OrdinalDate {
+        OrdinalDate { date: date }
+    }
+}
+
+impl From<OrdinalDate> for NaiveDate {
+    fn from(od: OrdinalDate) -> NaiveDate {
+        od.date
+    }
+}
+
+/// An addition of `Duration` to `OrdinalDate` discards the fractional days,
+/// in the same way as [`NaiveDate`](./struct.NaiveDate.html#impl-Add%3CDuration%3E).
+///
+/// Panics on underflow or overflow.
+impl Add<OldDuration> for OrdinalDate {
+    type Output = OrdinalDate;
+
+    #[inline]
+    fn add(self, rhs: OldDuration) -> OrdinalDate {
+        OrdinalDate { date: self.date + rhs }
+    }
+}
+
+/// A subtraction of `Duration` from `OrdinalDate` discards the fractional days,
+/// in the same way as [`NaiveDate`](./struct.NaiveDate.html#impl-Sub%3CDuration%3E).
+///
+/// Panics on underflow or overflow.
+impl Sub<OldDuration> for OrdinalDate {
+    type Output = OrdinalDate;
+
+    #[inline]
+    fn sub(self, rhs: OldDuration) -> OrdinalDate {
+        OrdinalDate { date: self.date - rhs }
+    }
+}
+
+/// The `Debug` output of the ordinal date `od` is same to
+/// [`od.to_naive_date().format("%Y-%j")`](../format/strftime/index.html).
+///
+/// ISO 8601 requires an explicit sign for years before 1 BCE or after 9999 CE.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::OrdinalDate;
+///
+/// assert_eq!(format!("{:?}", OrdinalDate::from_yo(2015, 248)), "2015-248");
+/// assert_eq!(format!("{:?}", OrdinalDate::from_yo(-1, 1)), "-0001-001");
+/// assert_eq!(format!("{:?}", OrdinalDate::from_yo(10000, 366)), "+10000-366");
+/// ~~~~
+impl fmt::Debug for OrdinalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let year = self.year();
+        let ordinal = self.ordinal();
+        if 0 <= year && year <= 9999 {
+            write!(f, "{:04}-{:03}", year, ordinal)
+        } else {
+            // ISO 8601 requires the explicit sign for out-of-range years
+            write!(f, "{:+05}-{:03}", year, ordinal)
+        }
+    }
+}
+
+/// The `Display` output of the ordinal date `od` is same to its `Debug` output.
+impl fmt::Display for OrdinalDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
+
+/// Parsing a `str` into an `OrdinalDate` uses the format like `2023-074`,
+/// which is the inverse of its `Display` output.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::OrdinalDate;
+///
+/// assert_eq!("2016-366".parse::<OrdinalDate>(), Ok(OrdinalDate::from_yo(2016, 366)));
+/// assert_eq!("-0001-001".parse::<OrdinalDate>(), Ok(OrdinalDate::from_yo(-1, 1)));
+/// assert!("2015-366".parse::<OrdinalDate>().is_err());
+/// assert!("2015-03-15".parse::<OrdinalDate>().is_err());
+/// ~~~~
+impl str::FromStr for OrdinalDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<OrdinalDate> {
+        const ITEMS: &'static [Item<'static>] = &[
+            Item::Space(""), Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::Space(""), Item::Literal("-"),
+            Item::Space(""), Item::Numeric(Numeric::Ordinal, Pad::Zero),
+            Item::Space(""),
+        ];
+
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, s, ITEMS.iter().cloned()));
+        parsed.to_naive_date().map(OrdinalDate::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::{NaiveDate, MIN_DATE, MAX_DATE};
+    use oldtime::Duration;
+    use Datelike;
+    use super::OrdinalDate;
+
+    #[test]
+    fn test_ordinal_date() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        for &(date, (y, o)) in &[(ymd(2023, 3, 15), (2023, 74)),
+                                 (ymd(2016, 12, 31), (2016, 366)),
+                                 (ymd(0, 1, 1), (0, 1)),
+                                 (MIN_DATE, (MIN_DATE.year(), 1)),
+                                 (MAX_DATE, (MAX_DATE.year(), MAX_DATE.ordinal()))] {
+            let od = OrdinalDate::from(date);
+            assert_eq!((od.year(), od.ordinal(), od.ordinal0()), (y, o, o - 1));
+            assert_eq!(NaiveDate::from(od), date);
+            assert_eq!(OrdinalDate::from_yo_opt(y, o), Some(od));
+            assert_eq!(od.to_string().parse::<OrdinalDate>(), Ok(od));
+        }
+
+        assert_eq!(OrdinalDate::from_yo_opt(2015, 366), None);
+        assert_eq!(OrdinalDate::from_yo_opt(MAX_DATE.year() + 1, 1), None);
+    }
+
+    #[test]
+    fn test_ordinal_date_add() {
+        let od = OrdinalDate::from_yo(2016, 360);
+        assert_eq!(od.checked_add_days(6), Some(OrdinalDate::from_yo(2016, 366)));
+        assert_eq!(od.checked_add_days(7), Some(OrdinalDate::from_yo(2017, 1)));
+        assert_eq!(od.checked_sub_days(360), Some(OrdinalDate::from_yo(2015, 365)));
+        assert_eq!(od + Duration::days(1), OrdinalDate::from_yo(2016, 361));
+        assert_eq!(od - Duration::days(1), OrdinalDate::from_yo(2016, 359));
+        assert_eq!(OrdinalDate::from(MAX_DATE).checked_add_days(1), None);
+        assert_eq!(OrdinalDate::from(MIN_DATE).checked_sub_days(1), None);
+    }
+}
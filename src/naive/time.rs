@@ -11,6 +11,7 @@ use Timelike;
 use div::div_mod_floor;
 use format::{Item, Numeric, Pad, Fixed};
 use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItems};
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
 
 /// ISO 8601 time without timezone.
 /// Allows for the nanosecond precision and optional leap second representation.
@@ -172,11 +173,18 @@ use format::{parse, Parsed, ParseError, ParseResult, DelayedFormat, StrftimeItem
 /// Since Chrono alone cannot determine any existence of leap seconds,
 /// **there is absolutely no guarantee that the leap second read has actually happened**.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct NaiveTime {
     secs: u32,
     frac: u32,
 }
 
+/// The earliest `NaiveTime` (midnight). For the internal use of other modules.
+pub const MIN_TIME: NaiveTime = NaiveTime { secs: 0, frac: 0 };
+/// The latest non-leap `NaiveTime` (the last nanosecond of the day).
+/// For the internal use of other modules.
+pub const MAX_TIME: NaiveTime = NaiveTime { secs: 86_399, frac: 999_999_999 };
+
 impl NaiveTime {
     /// Makes a new `NaiveTime` from hour, minute and second.
     ///
@@ -1417,6 +1425,37 @@ mod serde {
     }
 }
 
+#[cfg(feature = "quickcheck")]
+mod arbitrary {
+    use super::NaiveTime;
+    use quickcheck::{Arbitrary, Gen};
+    #[cfg(test)] use Timelike;
+
+    /// Generates a time uniformly distributed over a day,
+    /// occasionally being a leap second when the second is 59.
+    impl Arbitrary for NaiveTime {
+        fn arbitrary(g: &mut Gen) -> NaiveTime {
+            let secs = u32::arbitrary(g) % 86400;
+            let mut nano = u32::arbitrary(g) % 1_000_000_000;
+            if secs % 60 == 59 && bool::arbitrary(g) {
+                nano += 1_000_000_000;
+            }
+            NaiveTime::from_num_seconds_from_midnight_opt(secs, nano).unwrap()
+        }
+    }
+
+    #[cfg(test)]
+    quickcheck! {
+        fn prop_string_roundtrip(t: NaiveTime) -> bool {
+            t.to_string().parse::<NaiveTime>() == Ok(t)
+        }
+
+        fn prop_fields_in_range(t: NaiveTime) -> bool {
+            t.hour() < 24 && t.minute() < 60 && t.second() < 60 && t.nanosecond() < 2_000_000_000
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::NaiveTime;
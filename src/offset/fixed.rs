@@ -12,6 +12,7 @@ use div::div_mod_floor;
 use naive::{NaiveTime, NaiveDate, NaiveDateTime};
 use DateTime;
 use super::{TimeZone, Offset, LocalResult};
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
 
 /// The time zone with fixed offset, from UTC-23:59:59 to UTC+23:59:59.
 ///
@@ -20,6 +21,7 @@ use super::{TimeZone, Offset, LocalResult};
 /// `DateTime<FixedOffset>` instances. See the [`east`](#method.east) and
 /// [`west`](#method.west) methods for examples.
 #[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct FixedOffset {
     local_minus_utc: i32,
 }
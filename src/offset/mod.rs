@@ -310,6 +310,83 @@ pub trait TimeZone: Sized + Clone {
         }
     }
 
+    /// Makes a new `DateTime` from the number of non-leap milliseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// Panics on the out-of-range number of milliseconds,
+    /// for a non-panicking version see [`timestamp_millis_opt`](#method.timestamp_millis_opt).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone};
+    ///
+    /// assert_eq!(Utc.timestamp_millis(1431648000123).to_string(),
+    ///            "2015-05-15 00:00:00.123 UTC");
+    /// ~~~~
+    fn timestamp_millis(&self, millis: i64) -> DateTime<Self> {
+        self.timestamp_millis_opt(millis).unwrap()
+    }
+
+    /// Makes a new `DateTime` from the number of non-leap milliseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// Returns `LocalResult::None` on out-of-range number of milliseconds,
+    /// otherwise always returns `LocalResult::Single`.
+    fn timestamp_millis_opt(&self, millis: i64) -> LocalResult<DateTime<Self>> {
+        match NaiveDateTime::from_timestamp_millis_opt(millis) {
+            Some(dt) => LocalResult::Single(self.from_utc_datetime(&dt)),
+            None => LocalResult::None,
+        }
+    }
+
+    /// Makes a new `DateTime` from the number of non-leap microseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// Panics on the out-of-range number of microseconds,
+    /// for a non-panicking version see [`timestamp_micros_opt`](#method.timestamp_micros_opt).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone};
+    ///
+    /// assert_eq!(Utc.timestamp_micros(1431648000123456).to_string(),
+    ///            "2015-05-15 00:00:00.123456 UTC");
+    /// ~~~~
+    fn timestamp_micros(&self, micros: i64) -> DateTime<Self> {
+        self.timestamp_micros_opt(micros).unwrap()
+    }
+
+    /// Makes a new `DateTime` from the number of non-leap microseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// Returns `LocalResult::None` on out-of-range number of microseconds,
+    /// otherwise always returns `LocalResult::Single`.
+    fn timestamp_micros_opt(&self, micros: i64) -> LocalResult<DateTime<Self>> {
+        match NaiveDateTime::from_timestamp_micros_opt(micros) {
+            Some(dt) => LocalResult::Single(self.from_utc_datetime(&dt)),
+            None => LocalResult::None,
+        }
+    }
+
+    /// Makes a new `DateTime` from the number of non-leap nanoseconds
+    /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
+    ///
+    /// Every `i64` value is in range (from 1677 to 2262 CE), so this never panics.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Utc, TimeZone};
+    ///
+    /// assert_eq!(Utc.timestamp_nanos(1431648000123456789).to_string(),
+    ///            "2015-05-15 00:00:00.123456789 UTC");
+    /// ~~~~
+    fn timestamp_nanos(&self, nanos: i64) -> DateTime<Self> {
+        self.from_utc_datetime(&NaiveDateTime::from_timestamp_nanos(nanos))
+    }
+
     /// Parses a string with the specified format string and
     /// returns a `DateTime` with the current offset.
     /// See the [`format::strftime` module](../format/strftime/index.html)
@@ -372,8 +449,10 @@ pub trait TimeZone: Sized + Clone {
 mod utc;
 mod fixed;
 mod local;
+mod tai;
 
 pub use self::utc::Utc;
 pub use self::fixed::FixedOffset;
 pub use self::local::Local;
+pub use self::tai::{Tai, TaiOffset, LEAP_SECONDS};
 
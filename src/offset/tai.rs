@@ -0,0 +1,280 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The TAI (International Atomic Time) time scale.
+
+use std::fmt;
+
+use naive::{NaiveDate, NaiveDateTime};
+use {DateTime, Timelike};
+use super::{TimeZone, Offset, LocalResult, FixedOffset};
+
+/// The difference between TAI and UTC in seconds as of 1972-01-01,
+/// when UTC started to be adjusted only by the whole leap seconds.
+const INITIAL_TAI_MINUS_UTC: i32 = 10;
+
+/// The built-in table of leap seconds.
+///
+/// Each entry is the UNIX timestamp of the midnight (in UTC) right after an inserted leap second,
+/// i.e. the moment when the difference between TAI and UTC has been increased by one second.
+/// The last entry corresponds to the leap second inserted at the end of 2016,
+/// after which TAI is 37 seconds ahead of UTC.
+pub const LEAP_SECONDS: &'static [i64] = &[
+    78796800,   // 1972-07-01
+    94694400,   // 1973-01-01
+    126230400,  // 1974-01-01
+    157766400,  // 1975-01-01
+    189302400,  // 1976-01-01
+    220924800,  // 1977-01-01
+    252460800,  // 1978-01-01
+    283996800,  // 1979-01-01
+    315532800,  // 1980-01-01
+    362793600,  // 1981-07-01
+    394329600,  // 1982-07-01
+    425865600,  // 1983-07-01
+    489024000,  // 1985-07-01
+    567993600,  // 1988-01-01
+    631152000,  // 1990-01-01
+    662688000,  // 1991-01-01
+    709948800,  // 1992-07-01
+    741484800,  // 1993-07-01
+    773020800,  // 1994-07-01
+    820454400,  // 1996-01-01
+    867715200,  // 1997-07-01
+    915148800,  // 1999-01-01
+    1136073600, // 2006-01-01
+    1230768000, // 2009-01-01
+    1341100800, // 2012-07-01
+    1435708800, // 2015-07-01
+    1483228800, // 2017-01-01
+];
+
+/// The TAI (International Atomic Time) time scale.
+///
+/// TAI is ahead of UTC by a number of seconds which increases with every inserted leap second,
+/// so the local time in this "time zone" is a TAI clock reading.
+/// Unlike UTC, TAI has no leap seconds and every minute has exactly 60 seconds,
+/// so the differences between TAI clock readings are exact intervals.
+///
+/// The conversion is driven by a table of leap seconds,
+/// which defaults to the built-in [`LEAP_SECONDS`](./constant.LEAP_SECONDS.html)
+/// and can be replaced with `Tai::with_leap_seconds` when new leap seconds are announced.
+/// Before 1972 TAI is assumed to be 10 seconds ahead of UTC;
+/// the actual relation between them was not defined in terms of leap seconds back then.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{TimeZone, Utc, Tai};
+///
+/// let dt = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0).with_timezone(&Tai::new());
+/// assert_eq!(dt.to_string(), "2017-01-01 00:00:37 TAI");
+///
+/// // the leap second at the end of 2016 is a distinct TAI second
+/// let leap = Utc.ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500).with_timezone(&Tai::new());
+/// assert_eq!(leap.to_string(), "2017-01-01 00:00:36.500 TAI");
+/// ~~~~
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Tai {
+    leap_seconds: &'static [i64],
+}
+
+impl Tai {
+    /// Makes a new `Tai` with the built-in table of leap seconds.
+    pub fn new() -> Tai {
+        Tai { leap_seconds: LEAP_SECONDS }
+    }
+
+    /// Makes a new `Tai` with given table of leap seconds.
+    ///
+    /// The table should be sorted and use the same format as
+    /// [`LEAP_SECONDS`](./constant.LEAP_SECONDS.html),
+    /// i.e. list the UNIX timestamps of the midnights right after each inserted leap second.
+    pub fn with_leap_seconds(leap_seconds: &'static [i64]) -> Tai {
+        Tai { leap_seconds: leap_seconds }
+    }
+
+    /// Returns the table of leap seconds in use.
+    pub fn leap_seconds(&self) -> &'static [i64] {
+        self.leap_seconds
+    }
+
+    /// Returns the offset in effect at given UTC date and time.
+    ///
+    /// During a leap second the previous offset is still in effect;
+    /// its extra second is kept in the leap second representation of the local time.
+    fn offset_at(&self, utc: &NaiveDateTime) -> TaiOffset {
+        let timestamp = utc.timestamp();
+        let count = self.leap_seconds.iter().take_while(|&&t| t <= timestamp).count();
+        TaiOffset {
+            tai_minus_utc: INITIAL_TAI_MINUS_UTC + count as i32,
+            leap_seconds: self.leap_seconds,
+        }
+    }
+
+    /// Converts given TAI clock reading to the UTC date and time, along with the offset.
+    ///
+    /// The TAI second corresponding to an inserted leap second is mapped to
+    /// the leap second representation of the UTC time.
+    fn utc_from_tai(&self, tai: &NaiveDateTime) -> Option<(NaiveDateTime, TaiOffset)> {
+        let secs = tai.timestamp() + (tai.nanosecond() / 1_000_000_000) as i64;
+        let nsecs = tai.nanosecond() % 1_000_000_000;
+
+        let mut tai_minus_utc = INITIAL_TAI_MINUS_UTC;
+        for &t in self.leap_seconds {
+            let t_in_tai = t + tai_minus_utc as i64;
+            if secs < t_in_tai {
+                break;
+            }
+            if secs == t_in_tai {
+                let offset = TaiOffset { tai_minus_utc: tai_minus_utc,
+                                         leap_seconds: self.leap_seconds };
+                let utc = try_opt!(NaiveDateTime::from_timestamp_opt(t - 1,
+                                                                     nsecs + 1_000_000_000));
+                return Some((utc, offset));
+            }
+            tai_minus_utc += 1;
+        }
+
+        let offset = TaiOffset { tai_minus_utc: tai_minus_utc, leap_seconds: self.leap_seconds };
+        let utc = try_opt!(NaiveDateTime::from_timestamp_opt(secs - tai_minus_utc as i64, nsecs));
+        Some((utc, offset))
+    }
+}
+
+impl Default for Tai {
+    fn default() -> Tai {
+        Tai::new()
+    }
+}
+
+impl TimeZone for Tai {
+    type Offset = TaiOffset;
+
+    fn from_offset(offset: &TaiOffset) -> Tai {
+        Tai { leap_seconds: offset.leap_seconds }
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TaiOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TaiOffset> {
+        match self.utc_from_tai(local) {
+            Some((_, offset)) => LocalResult::Single(offset),
+            None => LocalResult::None,
+        }
+    }
+
+    fn from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<DateTime<Tai>> {
+        // the default implementation cannot produce the leap second representation
+        match self.utc_from_tai(local) {
+            Some((utc, offset)) => LocalResult::Single(DateTime::from_utc(utc, offset)),
+            None => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TaiOffset {
+        self.offset_at(&utc.and_hms(0, 0, 0))
+    }
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TaiOffset {
+        self.offset_at(utc)
+    }
+}
+
+impl fmt::Debug for Tai {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "TAI") }
+}
+
+/// The offset from UTC to TAI, which is the number of seconds TAI is ahead of UTC
+/// at the particular moment.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct TaiOffset {
+    tai_minus_utc: i32,
+    leap_seconds: &'static [i64],
+}
+
+impl TaiOffset {
+    /// Returns the number of seconds TAI is ahead of UTC.
+    pub fn tai_minus_utc(&self) -> i32 {
+        self.tai_minus_utc
+    }
+}
+
+impl Offset for TaiOffset {
+    fn fix(&self) -> FixedOffset { FixedOffset::east(self.tai_minus_utc) }
+}
+
+impl fmt::Debug for TaiOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TAI(UTC+{}s)", self.tai_minus_utc)
+    }
+}
+
+impl fmt::Display for TaiOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "TAI") }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::NaiveDate;
+    use offset::{TimeZone, Utc};
+    use oldtime::Duration;
+    use super::{Tai, LEAP_SECONDS};
+
+    #[test]
+    fn test_leap_seconds_table() {
+        for w in LEAP_SECONDS.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+        for &t in LEAP_SECONDS {
+            let dt = Utc.timestamp(t, 0);
+            assert_eq!(dt.time(), NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0).time());
+        }
+        let last = Utc.timestamp(*LEAP_SECONDS.last().unwrap(), 0);
+        assert_eq!(last, Utc.ymd(2017, 1, 1).and_hms(0, 0, 0));
+        assert_eq!(Tai::new().offset_from_utc_datetime(&last.naive_utc()).tai_minus_utc(), 37);
+    }
+
+    #[test]
+    fn test_tai_offsets() {
+        let tai = Tai::new();
+        let offset = |y, m, d| tai.offset_from_utc_date(&NaiveDate::from_ymd(y, m, d))
+                                  .tai_minus_utc();
+        assert_eq!(offset(1960, 1, 1), 10);
+        assert_eq!(offset(1972, 1, 1), 10);
+        assert_eq!(offset(1972, 7, 1), 11);
+        assert_eq!(offset(1999, 1, 1), 32);
+        assert_eq!(offset(2016, 12, 31), 36);
+        assert_eq!(offset(2017, 1, 1), 37);
+        assert_eq!(offset(2100, 1, 1), 37);
+    }
+
+    #[test]
+    fn test_tai_leap_second() {
+        let tai = Tai::new();
+        let before = Utc.ymd(2016, 12, 31).and_hms(23, 59, 59);
+        let leap = Utc.ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500);
+        let after = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0);
+
+        let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd(y, m, d).and_hms(h, n, s);
+        assert_eq!(before.with_timezone(&tai).to_string(), "2017-01-01 00:00:35 TAI");
+        assert_eq!(leap.with_timezone(&tai).to_string(), "2017-01-01 00:00:36.500 TAI");
+        assert_eq!(after.with_timezone(&tai).to_string(), "2017-01-01 00:00:37 TAI");
+
+        // round trips through the TAI clock readings
+        assert_eq!(tai.from_local_datetime(&ymdhms(2017, 1, 1, 0, 0, 35)).unwrap(), before);
+        let leap_reading = ymdhms(2017, 1, 1, 0, 0, 36) + Duration::milliseconds(500);
+        assert_eq!(tai.from_local_datetime(&leap_reading).unwrap(), leap);
+        assert_eq!(tai.from_local_datetime(&ymdhms(2017, 1, 1, 0, 0, 37)).unwrap(), after);
+        assert_eq!(tai.ymd(2017, 1, 1).and_hms(0, 0, 37), after);
+    }
+
+    #[test]
+    fn test_tai_custom_leap_seconds() {
+        static TABLE: [i64; 1] = [1483228800];
+        let tai = Tai::with_leap_seconds(&TABLE);
+        let dt = Utc.ymd(2017, 1, 1).and_hms(0, 0, 0).with_timezone(&tai);
+        assert_eq!(dt.offset().tai_minus_utc(), 11);
+        assert_eq!(dt.timezone(), tai);
+    }
+}
@@ -9,6 +9,7 @@ use oldtime;
 use naive::{NaiveDate, NaiveDateTime};
 use {Date, DateTime};
 use super::{TimeZone, Offset, LocalResult, FixedOffset};
+#[cfg(feature = "rkyv")] use rkyv::{Archive, Deserialize, Serialize};
 
 /// The UTC time zone. This is the most efficient time zone when you don't need the local time.
 /// It is also used as an offset (which is also a dummy type).
@@ -28,6 +29,7 @@ use super::{TimeZone, Offset, LocalResult, FixedOffset};
 /// assert_eq!(Utc.ymd(1970, 1, 1).and_hms(0, 1, 1), dt);
 /// ~~~~
 #[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, Deserialize, Serialize))]
 pub struct Utc;
 
 impl Utc {
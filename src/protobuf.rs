@@ -0,0 +1,246 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Conversions from and to the `google.protobuf.Timestamp` and `google.protobuf.Duration`
+//! [well-known types][wkt].
+//!
+//! [`Timestamp`](./struct.Timestamp.html) and [`Duration`](./struct.Duration.html) here
+//! have the same `seconds` and `nanos` fields as the messages,
+//! so they can be filled from or into the message types generated by any protobuf library.
+//! The conversions check the ranges given by the specification of the messages.
+//!
+//! ~~~~
+//! use std::convert::TryFrom;
+//! use chrono::{DateTime, Utc, TimeZone};
+//! use chrono::protobuf::Timestamp;
+//!
+//! let dt = Utc.ymd(2017, 1, 15).and_hms_nano(1, 30, 15, 10_000);
+//! let ts = Timestamp::try_from(dt).unwrap();
+//! assert_eq!((ts.seconds, ts.nanos), (1484443815, 10_000));
+//! assert_eq!(DateTime::<Utc>::try_from(ts), Ok(dt));
+//!
+//! // years before 1 or after 9999 are not allowed
+//! assert!(DateTime::<Utc>::try_from(Timestamp { seconds: -62135596801, nanos: 0 }).is_err());
+//! ~~~~
+//!
+//! [wkt]: https://developers.google.com/protocol-buffers/docs/reference/google.protobuf
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::error::Error;
+use oldtime::Duration as OldDuration;
+
+use offset::{TimeZone, Utc};
+use DateTime;
+
+/// The number of seconds from the UNIX epoch to 0001-01-01T00:00:00Z.
+const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+/// The number of seconds from the UNIX epoch to 9999-12-31T23:59:59Z.
+const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+/// The number of seconds in 10,000 years, as approximated by the specification.
+const MAX_DURATION_SECONDS: i64 = 315_576_000_000;
+const MAX_NANOS: i32 = 999_999_999;
+
+/// The fields of a `google.protobuf.Timestamp` message.
+///
+/// A valid timestamp is between 0001-01-01T00:00:00Z and 9999-12-31T23:59:59.999999999Z
+/// and has `nanos` between 0 and 999,999,999.
+/// Leap seconds are not representable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Timestamp {
+    /// The number of seconds since the UNIX epoch.
+    pub seconds: i64,
+    /// The non-negative fraction of a second in nanoseconds.
+    pub nanos: i32,
+}
+
+/// The fields of a `google.protobuf.Duration` message.
+///
+/// A valid duration spans about 10,000 years at most in either direction,
+/// and `nanos` has the same sign as `seconds` unless either is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Duration {
+    /// The signed number of seconds.
+    pub seconds: i64,
+    /// The signed fraction of a second in nanoseconds.
+    pub nanos: i32,
+}
+
+/// An error from converting from or to the protobuf types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeError {
+    /// The number of seconds is out of the range allowed for the message.
+    SecondsOutOfRange,
+    /// The number of nanoseconds is out of the range allowed for the message,
+    /// or the value is a leap second.
+    NanosOutOfRange,
+    /// The `seconds` and `nanos` of a `Duration` have different signs.
+    MismatchedSigns,
+}
+
+impl RangeError {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            RangeError::SecondsOutOfRange => "seconds out of range",
+            RangeError::NanosOutOfRange => "nanoseconds out of range",
+            RangeError::MismatchedSigns => "seconds and nanoseconds have different signs",
+        }
+    }
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Error for RangeError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<(i64, i32)> for Timestamp {
+    fn from((seconds, nanos): (i64, i32)) -> Timestamp {
+        Timestamp { seconds: seconds, nanos: nanos }
+    }
+}
+
+impl From<Timestamp> for (i64, i32) {
+    fn from(ts: Timestamp) -> (i64, i32) {
+        (ts.seconds, ts.nanos)
+    }
+}
+
+impl From<(i64, i32)> for Duration {
+    fn from((seconds, nanos): (i64, i32)) -> Duration {
+        Duration { seconds: seconds, nanos: nanos }
+    }
+}
+
+impl From<Duration> for (i64, i32) {
+    fn from(d: Duration) -> (i64, i32) {
+        (d.seconds, d.nanos)
+    }
+}
+
+impl TryFrom<Timestamp> for DateTime<Utc> {
+    type Error = RangeError;
+
+    fn try_from(ts: Timestamp) -> Result<DateTime<Utc>, RangeError> {
+        if ts.seconds < MIN_TIMESTAMP_SECONDS || ts.seconds > MAX_TIMESTAMP_SECONDS {
+            return Err(RangeError::SecondsOutOfRange);
+        }
+        if ts.nanos < 0 || ts.nanos > MAX_NANOS {
+            return Err(RangeError::NanosOutOfRange);
+        }
+        Ok(Utc.timestamp(ts.seconds, ts.nanos as u32))
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for Timestamp {
+    type Error = RangeError;
+
+    fn try_from(dt: DateTime<Tz>) -> Result<Timestamp, RangeError> {
+        let seconds = dt.timestamp();
+        if seconds < MIN_TIMESTAMP_SECONDS || seconds > MAX_TIMESTAMP_SECONDS {
+            return Err(RangeError::SecondsOutOfRange);
+        }
+        let nanos = dt.timestamp_subsec_nanos();
+        if nanos > MAX_NANOS as u32 {
+            return Err(RangeError::NanosOutOfRange);
+        }
+        Ok(Timestamp { seconds: seconds, nanos: nanos as i32 })
+    }
+}
+
+impl TryFrom<Duration> for OldDuration {
+    type Error = RangeError;
+
+    fn try_from(d: Duration) -> Result<OldDuration, RangeError> {
+        if d.seconds < -MAX_DURATION_SECONDS || d.seconds > MAX_DURATION_SECONDS {
+            return Err(RangeError::SecondsOutOfRange);
+        }
+        if d.nanos < -MAX_NANOS || d.nanos > MAX_NANOS {
+            return Err(RangeError::NanosOutOfRange);
+        }
+        if (d.seconds < 0 && d.nanos > 0) || (d.seconds > 0 && d.nanos < 0) {
+            return Err(RangeError::MismatchedSigns);
+        }
+        Ok(OldDuration::seconds(d.seconds) + OldDuration::nanoseconds(d.nanos as i64))
+    }
+}
+
+impl TryFrom<OldDuration> for Duration {
+    type Error = RangeError;
+
+    fn try_from(d: OldDuration) -> Result<Duration, RangeError> {
+        // `num_seconds` truncates towards zero, so `nanos` gets the same sign
+        let seconds = d.num_seconds();
+        if seconds < -MAX_DURATION_SECONDS || seconds > MAX_DURATION_SECONDS {
+            return Err(RangeError::SecondsOutOfRange);
+        }
+        let nanos = (d - OldDuration::seconds(seconds)).num_nanoseconds().unwrap();
+        Ok(Duration { seconds: seconds, nanos: nanos as i32 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::{Timestamp, Duration, RangeError};
+    use oldtime::Duration as OldDuration;
+    use offset::{TimeZone, Utc, FixedOffset};
+    use DateTime;
+
+    #[test]
+    fn test_timestamp() {
+        let from_ts = |seconds, nanos| DateTime::<Utc>::try_from(Timestamp::from((seconds, nanos)));
+
+        assert_eq!(from_ts(0, 0), Ok(Utc.ymd(1970, 1, 1).and_hms(0, 0, 0)));
+        assert_eq!(from_ts(-1, 999_999_999),
+                   Ok(Utc.ymd(1969, 12, 31).and_hms_nano(23, 59, 59, 999_999_999)));
+        assert_eq!(from_ts(-62135596800, 0), Ok(Utc.ymd(1, 1, 1).and_hms(0, 0, 0)));
+        assert_eq!(from_ts(253402300799, 999_999_999),
+                   Ok(Utc.ymd(9999, 12, 31).and_hms_nano(23, 59, 59, 999_999_999)));
+        assert_eq!(from_ts(-62135596801, 0), Err(RangeError::SecondsOutOfRange));
+        assert_eq!(from_ts(253402300800, 0), Err(RangeError::SecondsOutOfRange));
+        assert_eq!(from_ts(0, -1), Err(RangeError::NanosOutOfRange));
+        assert_eq!(from_ts(0, 1_000_000_000), Err(RangeError::NanosOutOfRange));
+
+        let dt = FixedOffset::east(9 * 3600).ymd(2017, 1, 15).and_hms_milli(10, 30, 15, 250);
+        assert_eq!(Timestamp::try_from(dt),
+                   Ok(Timestamp { seconds: 1484443815, nanos: 250_000_000 }));
+        assert_eq!(Timestamp::try_from(Utc.ymd(10000, 1, 1).and_hms(0, 0, 0)),
+                   Err(RangeError::SecondsOutOfRange));
+        assert_eq!(Timestamp::try_from(Utc.ymd(0, 12, 31).and_hms(23, 59, 59)),
+                   Err(RangeError::SecondsOutOfRange));
+        assert_eq!(Timestamp::try_from(Utc.ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500)),
+                   Err(RangeError::NanosOutOfRange));
+        assert_eq!(<(i64, i32)>::from(Timestamp::try_from(dt).unwrap()), (1484443815, 250_000_000));
+    }
+
+    #[test]
+    fn test_duration() {
+        let from_pb = |seconds, nanos| OldDuration::try_from(Duration::from((seconds, nanos)));
+
+        assert_eq!(from_pb(1, 500_000_000), Ok(OldDuration::milliseconds(1_500)));
+        assert_eq!(from_pb(-1, -500_000_000), Ok(OldDuration::milliseconds(-1_500)));
+        assert_eq!(from_pb(0, -1), Ok(OldDuration::nanoseconds(-1)));
+        assert_eq!(from_pb(315_576_000_000, 0), Ok(OldDuration::seconds(315_576_000_000)));
+        assert_eq!(from_pb(315_576_000_001, 0), Err(RangeError::SecondsOutOfRange));
+        assert_eq!(from_pb(-315_576_000_001, 0), Err(RangeError::SecondsOutOfRange));
+        assert_eq!(from_pb(0, 1_000_000_000), Err(RangeError::NanosOutOfRange));
+        assert_eq!(from_pb(1, -1), Err(RangeError::MismatchedSigns));
+        assert_eq!(from_pb(-1, 1), Err(RangeError::MismatchedSigns));
+
+        assert_eq!(Duration::try_from(OldDuration::milliseconds(-1_500)),
+                   Ok(Duration { seconds: -1, nanos: -500_000_000 }));
+        assert_eq!(Duration::try_from(OldDuration::nanoseconds(999_999_999)),
+                   Ok(Duration { seconds: 0, nanos: 999_999_999 }));
+        assert_eq!(Duration::try_from(OldDuration::seconds(315_576_000_001)),
+                   Err(RangeError::SecondsOutOfRange));
+        assert_eq!(<(i64, i32)>::from(Duration::try_from(OldDuration::seconds(-3)).unwrap()),
+                   (-3, 0));
+    }
+}
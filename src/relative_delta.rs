@@ -0,0 +1,364 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A combination of absolute and relative changes to a date and time,
+//! modelled after `relativedelta` of Python's `dateutil`.
+
+use std::cmp;
+use std::ops::{Add, Sub, Neg};
+use num::traits::ToPrimitive;
+use oldtime::Duration as OldDuration;
+
+use {Weekday, Datelike, Timelike};
+use div::div_mod_floor;
+use offset::TimeZone;
+use naive::{NaiveDate, NaiveTime, NaiveDateTime};
+use DateTime;
+
+/// A set of absolute and relative changes to a date and time,
+/// like `relativedelta` of Python's `dateutil`.
+///
+/// The absolute fields (`year`, `month`, `day`, `weekday`, `hour` and so on)
+/// replace the corresponding field, and the relative fields (`years`, `months`, `days` and so on)
+/// are added to the value. They are applied in the following order:
+///
+/// 1. The absolute `year` and `month` replace the year and month.
+/// 2. The relative `years` and `months` are added to the year and month.
+/// 3. The day is replaced by the absolute `day` if any, then clamped to
+///    the last day of the resulting month.
+/// 4. The absolute `hour`, `minute`, `second` and `nanosecond` replace the time.
+/// 5. The relative `weeks`, `days`, `hours`, `minutes`, `seconds` and `nanoseconds` are added,
+///    where a day is always 86,400 seconds.
+/// 6. If `weekday(wd, n)` is given, the result moves to the `n`-th `wd` counted from
+///    the result itself: `n = 1` stays or moves forward to the next `wd`,
+///    `n = -1` stays or moves backward to the previous `wd`, and so on.
+///
+/// Negating a `RelativeDelta` negates the relative fields only,
+/// so subtracting it still replaces the absolute fields.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, RelativeDelta, Weekday};
+///
+/// let d = NaiveDate::from_ymd(2003, 9, 17).and_hms(20, 54, 47);
+///
+/// // next month, clamped to the end of the month
+/// let next_month = RelativeDelta::new().months(1).day(31);
+/// assert_eq!(d + next_month, NaiveDate::from_ymd(2003, 10, 31).and_hms(20, 54, 47));
+///
+/// // the second Monday of the next month, at midnight
+/// let second_monday = RelativeDelta::new().months(1).day(1).weekday(Weekday::Mon, 2)
+///                                         .hour(0).minute(0).second(0);
+/// assert_eq!(d + second_monday, NaiveDate::from_ymd(2003, 10, 13).and_hms(0, 0, 0));
+///
+/// // the last Friday of the year
+/// let last_friday = RelativeDelta::new().month(12).day(31).weekday(Weekday::Fri, -1);
+/// assert_eq!(d.date() + last_friday, NaiveDate::from_ymd(2003, 12, 26));
+/// ~~~~
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativeDelta {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    weekday: Option<(Weekday, i32)>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+    months: i64,
+    duration: OldDuration,
+}
+
+impl RelativeDelta {
+    /// Makes a new `RelativeDelta` which changes nothing.
+    pub fn new() -> RelativeDelta {
+        RelativeDelta {
+            year: None, month: None, day: None, weekday: None,
+            hour: None, minute: None, second: None, nanosecond: None,
+            months: 0, duration: OldDuration::zero(),
+        }
+    }
+
+    /// Replaces the year.
+    pub fn year(self, year: i32) -> RelativeDelta {
+        RelativeDelta { year: Some(year), ..self }
+    }
+
+    /// Replaces the month, starting from 1.
+    pub fn month(self, month: u32) -> RelativeDelta {
+        RelativeDelta { month: Some(month), ..self }
+    }
+
+    /// Replaces the day of month, starting from 1.
+    /// It is clamped to the last day of the month when applied.
+    pub fn day(self, day: u32) -> RelativeDelta {
+        RelativeDelta { day: Some(day), ..self }
+    }
+
+    /// Moves to the `n`-th given day of week, counted from the date after other changes.
+    /// `n` of zero is treated as 1.
+    pub fn weekday(self, weekday: Weekday, n: i32) -> RelativeDelta {
+        RelativeDelta { weekday: Some((weekday, n)), ..self }
+    }
+
+    /// Replaces the hour.
+    pub fn hour(self, hour: u32) -> RelativeDelta {
+        RelativeDelta { hour: Some(hour), ..self }
+    }
+
+    /// Replaces the minute.
+    pub fn minute(self, minute: u32) -> RelativeDelta {
+        RelativeDelta { minute: Some(minute), ..self }
+    }
+
+    /// Replaces the second.
+    pub fn second(self, second: u32) -> RelativeDelta {
+        RelativeDelta { second: Some(second), ..self }
+    }
+
+    /// Replaces the fraction of a second in nanoseconds.
+    pub fn nanosecond(self, nanosecond: u32) -> RelativeDelta {
+        RelativeDelta { nanosecond: Some(nanosecond), ..self }
+    }
+
+    /// Adds given number of years.
+    ///
+    /// Panics when the total of the `years` and `months` fields overflows.
+    pub fn years(self, years: i32) -> RelativeDelta {
+        let months = self.months.checked_add(years as i64 * 12);
+        RelativeDelta { months: months.expect("`RelativeDelta::years` overflowed"), ..self }
+    }
+
+    /// Adds given number of months.
+    ///
+    /// Panics when the total of the `years` and `months` fields overflows.
+    pub fn months(self, months: i32) -> RelativeDelta {
+        let months = self.months.checked_add(months as i64);
+        RelativeDelta { months: months.expect("`RelativeDelta::months` overflowed"), ..self }
+    }
+
+    /// Adds given number of weeks.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn weeks(self, weeks: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::weeks(weeks), ..self }
+    }
+
+    /// Adds given number of days.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn days(self, days: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::days(days), ..self }
+    }
+
+    /// Adds given number of hours.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn hours(self, hours: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::hours(hours), ..self }
+    }
+
+    /// Adds given number of minutes.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn minutes(self, minutes: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::minutes(minutes), ..self }
+    }
+
+    /// Adds given number of seconds.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn seconds(self, seconds: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::seconds(seconds), ..self }
+    }
+
+    /// Adds given number of nanoseconds.
+    ///
+    /// Panics when the total of the `weeks` to `nanoseconds` fields overflows `Duration`.
+    pub fn nanoseconds(self, nanoseconds: i64) -> RelativeDelta {
+        RelativeDelta { duration: self.duration + OldDuration::nanoseconds(nanoseconds), ..self }
+    }
+
+    /// Applies the changes to given date and time.
+    ///
+    /// Returns `None` when it will result in overflow,
+    /// or an absolute field is out of its range (e.g. `month(13)` or `hour(24)`).
+    pub fn checked_apply(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let year = self.year.unwrap_or(datetime.year());
+        let month = self.month.unwrap_or(datetime.month());
+        if month < 1 || month > 12 {
+            return None;
+        }
+        let months = try_opt!((year as i64 * 12 + (month - 1) as i64).checked_add(self.months));
+        let (year, month0) = div_mod_floor(months, 12);
+        let year = try_opt!(year.to_i32());
+        let month = month0 as u32 + 1;
+
+        let day = self.day.unwrap_or(datetime.day());
+        if day < 1 {
+            return None;
+        }
+        let first = try_opt!(NaiveDate::from_ymd_opt(year, month, 1));
+        let date = try_opt!(first.with_day(cmp::min(day, first.days_in_month())));
+        let time = try_opt!(NaiveTime::from_hms_nano_opt(
+            self.hour.unwrap_or(datetime.hour()),
+            self.minute.unwrap_or(datetime.minute()),
+            self.second.unwrap_or(datetime.second()),
+            self.nanosecond.unwrap_or(datetime.nanosecond())));
+        let datetime = try_opt!(date.and_time(time).checked_add_signed(self.duration));
+
+        match self.weekday {
+            Some((weekday, n)) => {
+                let n = if n == 0 { 1 } else { n as i64 };
+                let from = datetime.weekday().num_days_from_monday() as i64;
+                let to = weekday.num_days_from_monday() as i64;
+                let jump = if n > 0 {
+                    (n - 1) * 7 + (7 + to - from) % 7
+                } else {
+                    (n + 1) * 7 - (7 + from - to) % 7
+                };
+                datetime.checked_add_signed(OldDuration::days(jump))
+            }
+            None => Some(datetime),
+        }
+    }
+}
+
+impl Default for RelativeDelta {
+    fn default() -> RelativeDelta {
+        RelativeDelta::new()
+    }
+}
+
+impl Neg for RelativeDelta {
+    type Output = RelativeDelta;
+
+    fn neg(self) -> RelativeDelta {
+        RelativeDelta { months: -self.months, duration: -self.duration, ..self }
+    }
+}
+
+/// The time fields are applied to the midnight of the date,
+/// and the date part of the result is taken.
+impl Add<RelativeDelta> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn add(self, rhs: RelativeDelta) -> NaiveDate {
+        rhs.checked_apply(self.and_hms(0, 0, 0)).expect("`NaiveDate + RelativeDelta` out of range")
+                                                .date()
+    }
+}
+
+impl Sub<RelativeDelta> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn sub(self, rhs: RelativeDelta) -> NaiveDate {
+        (-rhs).checked_apply(self.and_hms(0, 0, 0))
+              .expect("`NaiveDate - RelativeDelta` out of range")
+              .date()
+    }
+}
+
+impl Add<RelativeDelta> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    fn add(self, rhs: RelativeDelta) -> NaiveDateTime {
+        rhs.checked_apply(self).expect("`NaiveDateTime + RelativeDelta` out of range")
+    }
+}
+
+impl Sub<RelativeDelta> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    fn sub(self, rhs: RelativeDelta) -> NaiveDateTime {
+        (-rhs).checked_apply(self).expect("`NaiveDateTime - RelativeDelta` out of range")
+    }
+}
+
+/// The changes are applied to the local date and time.
+///
+/// Panics on overflow, or when the resulting local time does not exist or is ambiguous
+/// in the time zone (e.g. it falls in a gap or an overlap around a daylight saving time change).
+/// To handle these cases, use [`checked_apply`](./struct.RelativeDelta.html#method.checked_apply)
+/// on [`naive_local`](./struct.DateTime.html#method.naive_local) and
+/// convert the result back with
+/// [`TimeZone::from_local_datetime`](./offset/trait.TimeZone.html#method.from_local_datetime).
+impl<Tz: TimeZone> Add<RelativeDelta> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    fn add(self, rhs: RelativeDelta) -> DateTime<Tz> {
+        rhs.checked_apply(self.naive_local())
+           .and_then(|datetime| self.timezone().from_local_datetime(&datetime).single())
+           .expect("`DateTime + RelativeDelta` out of range")
+    }
+}
+
+/// The negated changes are applied to the local date and time.
+///
+/// Panics in the same cases as adding a `RelativeDelta`;
+/// use `checked_apply` with the negated `RelativeDelta` to handle them.
+impl<Tz: TimeZone> Sub<RelativeDelta> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    fn sub(self, rhs: RelativeDelta) -> DateTime<Tz> {
+        (-rhs).checked_apply(self.naive_local())
+              .and_then(|datetime| self.timezone().from_local_datetime(&datetime).single())
+              .expect("`DateTime - RelativeDelta` out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Weekday;
+    use super::RelativeDelta;
+    use offset::{TimeZone, FixedOffset};
+    use naive::{NaiveDate, MAX_DATE};
+
+    #[test]
+    fn test_relative_delta() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let dt = ymd(2003, 9, 17).and_hms(20, 54, 47);
+        let rd = RelativeDelta::new;
+
+        assert_eq!(dt + rd(), dt);
+        assert_eq!(dt + rd().years(1).months(-2), ymd(2004, 7, 17).and_hms(20, 54, 47));
+        assert_eq!(dt + rd().months(-9), ymd(2002, 12, 17).and_hms(20, 54, 47));
+        assert_eq!(dt + rd().year(2000).month(2).day(31), ymd(2000, 2, 29).and_hms(20, 54, 47));
+        assert_eq!(dt + rd().year(2001).month(2).day(31), ymd(2001, 2, 28).and_hms(20, 54, 47));
+        assert_eq!(ymd(2016, 1, 31) + rd().months(1), ymd(2016, 2, 29));
+        assert_eq!(dt + rd().hours(4).minutes(6), ymd(2003, 9, 18).and_hms(1, 0, 47));
+        assert_eq!(dt + rd().hour(10).weeks(1).days(-1), ymd(2003, 9, 23).and_hms(10, 54, 47));
+
+        // absolute fields are applied before relative ones
+        assert_eq!(dt + rd().days(1).day(30), ymd(2003, 10, 1).and_hms(20, 54, 47));
+        assert_eq!(dt + rd().months(1).month(1), ymd(2003, 2, 17).and_hms(20, 54, 47));
+
+        // 2003-09-17 is a Wednesday
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Wed, 1), ymd(2003, 9, 17));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Wed, 0), ymd(2003, 9, 17));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Mon, 1), ymd(2003, 9, 22));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Mon, 2), ymd(2003, 9, 29));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Wed, -1), ymd(2003, 9, 17));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Mon, -1), ymd(2003, 9, 15));
+        assert_eq!(ymd(2003, 9, 17) + rd().weekday(Weekday::Thu, -2), ymd(2003, 9, 4));
+        assert_eq!(ymd(2003, 9, 17) + rd().days(1).weekday(Weekday::Wed, 1), ymd(2003, 9, 24));
+
+        // negation only affects the relative fields
+        assert_eq!(dt - rd().months(1).day(31), ymd(2003, 8, 31).and_hms(20, 54, 47));
+        assert_eq!(dt - rd().years(1).hours(21), ymd(2002, 9, 16).and_hms(23, 54, 47));
+        assert_eq!(-(-rd().months(3).seconds(5)), rd().months(3).seconds(5));
+
+        assert_eq!(rd().month(13).checked_apply(dt), None);
+        assert_eq!(rd().day(0).checked_apply(dt), None);
+        assert_eq!(rd().hour(24).checked_apply(dt), None);
+        assert_eq!(rd().years(1).checked_apply(MAX_DATE.and_hms(0, 0, 0)), None);
+        assert_eq!(rd().weekday(Weekday::Mon, 2).checked_apply(MAX_DATE.and_hms(0, 0, 0)), None);
+
+        let edt = FixedOffset::west(4 * 3600);
+        assert_eq!(edt.ymd(2016, 3, 31).and_hms(23, 0, 0) + rd().months(1).hour(1),
+                   edt.ymd(2016, 4, 30).and_hms(1, 0, 0));
+        assert_eq!(edt.ymd(2016, 3, 31).and_hms(23, 0, 0) - rd().months(1),
+                   edt.ymd(2016, 2, 29).and_hms(23, 0, 0));
+    }
+}
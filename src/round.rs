@@ -0,0 +1,220 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Rounding of date and time values to a multiple of a `Duration`.
+
+use std::fmt;
+use std::error::Error;
+use oldtime::Duration as OldDuration;
+
+use offset::TimeZone;
+use naive::NaiveDateTime;
+use DateTime;
+
+/// Extension trait for rounding or truncating a date and time value
+/// to a multiple of a given [`Duration`](../struct.Duration.html).
+///
+/// The multiples are counted from the UNIX epoch (January 1, 1970 0:00:00).
+/// For the timezone-aware types this is done on the *local* date and time,
+/// so rounding to a day lands on the local midnight.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{DurationRound, Duration, NaiveDate};
+///
+/// let dt = NaiveDate::from_ymd(2018, 1, 11).and_hms_milli(12, 7, 31, 154);
+/// assert_eq!(dt.duration_round(Duration::minutes(10)).unwrap().to_string(),
+///            "2018-01-11 12:10:00");
+/// assert_eq!(dt.duration_trunc(Duration::milliseconds(10)).unwrap().to_string(),
+///            "2018-01-11 12:07:31.150");
+/// assert_eq!(dt.duration_ceil(Duration::hours(1)).unwrap().to_string(),
+///            "2018-01-11 13:00:00");
+/// ~~~~
+pub trait DurationRound: Sized {
+    /// Rounds to the nearest multiple of `duration`.
+    /// A value exactly halfway between two multiples is rounded up.
+    fn duration_round(self, duration: OldDuration) -> Result<Self, RoundingError>;
+
+    /// Truncates to the greatest multiple of `duration` not greater than the value.
+    fn duration_trunc(self, duration: OldDuration) -> Result<Self, RoundingError>;
+
+    /// Rounds up to the least multiple of `duration` not less than the value.
+    fn duration_ceil(self, duration: OldDuration) -> Result<Self, RoundingError>;
+}
+
+/// An error from the [`DurationRound`](./trait.DurationRound.html) methods.
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum RoundingError {
+    /// The duration is zero or negative.
+    DurationNotPositive,
+
+    /// The duration can not be represented in nanoseconds (about 292 years).
+    DurationExceedsLimit,
+
+    /// The value can not be represented as nanoseconds since the UNIX epoch,
+    /// or the rounded value would be out of range.
+    TimestampExceedsLimit,
+}
+
+impl RoundingError {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            RoundingError::DurationNotPositive => "duration is not positive",
+            RoundingError::DurationExceedsLimit => "duration exceeds the nanosecond limit",
+            RoundingError::TimestampExceedsLimit => "timestamp exceeds the nanosecond limit",
+        }
+    }
+}
+
+impl fmt::Display for RoundingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Error for RoundingError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode { Round, Trunc, Ceil }
+
+/// Returns the signed number of nanoseconds to be added to `naive`
+/// so that it becomes a multiple of `duration` according to `mode`.
+fn adjustment(naive: &NaiveDateTime, duration: OldDuration,
+              mode: Mode) -> Result<i64, RoundingError> {
+    let span = match duration.num_nanoseconds() {
+        Some(span) if span > 0 => span,
+        Some(_) => return Err(RoundingError::DurationNotPositive),
+        None => return Err(RoundingError::DurationExceedsLimit),
+    };
+    let stamp = naive.timestamp().checked_mul(1_000_000_000)
+                     .and_then(|ns| ns.checked_add(naive.timestamp_subsec_nanos() as i64))
+                     .ok_or(RoundingError::TimestampExceedsLimit)?;
+
+    // `delta_down` is in `0..span` regardless of the sign of `stamp`
+    let delta_down = ((stamp % span) + span) % span;
+    if delta_down == 0 {
+        return Ok(0);
+    }
+    let delta_up = span - delta_down;
+    Ok(match mode {
+        Mode::Trunc => -delta_down,
+        Mode::Ceil => delta_up,
+        Mode::Round => if delta_up <= delta_down { delta_up } else { -delta_down },
+    })
+}
+
+impl DurationRound for NaiveDateTime {
+    fn duration_round(self, duration: OldDuration) -> Result<NaiveDateTime, RoundingError> {
+        let delta = adjustment(&self, duration, Mode::Round)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+
+    fn duration_trunc(self, duration: OldDuration) -> Result<NaiveDateTime, RoundingError> {
+        let delta = adjustment(&self, duration, Mode::Trunc)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+
+    fn duration_ceil(self, duration: OldDuration) -> Result<NaiveDateTime, RoundingError> {
+        let delta = adjustment(&self, duration, Mode::Ceil)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+}
+
+impl<Tz: TimeZone> DurationRound for DateTime<Tz> {
+    fn duration_round(self, duration: OldDuration) -> Result<DateTime<Tz>, RoundingError> {
+        let delta = adjustment(&self.naive_local(), duration, Mode::Round)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+
+    fn duration_trunc(self, duration: OldDuration) -> Result<DateTime<Tz>, RoundingError> {
+        let delta = adjustment(&self.naive_local(), duration, Mode::Trunc)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+
+    fn duration_ceil(self, duration: OldDuration) -> Result<DateTime<Tz>, RoundingError> {
+        let delta = adjustment(&self.naive_local(), duration, Mode::Ceil)?;
+        self.checked_add_signed(OldDuration::nanoseconds(delta))
+            .ok_or(RoundingError::TimestampExceedsLimit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DurationRound, RoundingError};
+    use oldtime::Duration;
+    use offset::{TimeZone, Utc, FixedOffset};
+    use naive::NaiveDate;
+
+    #[test]
+    fn test_duration_round() {
+        let dt = Utc.ymd(2016, 12, 31).and_hms_nano(23, 59, 59, 175_500_000);
+        assert_eq!(dt.duration_round(Duration::milliseconds(10)).unwrap().to_string(),
+                   "2016-12-31 23:59:59.180 UTC");
+        assert_eq!(dt.duration_round(Duration::minutes(5)).unwrap().to_string(),
+                   "2017-01-01 00:00:00 UTC");
+        assert_eq!(dt.duration_round(Duration::days(1)).unwrap().to_string(),
+                   "2017-01-01 00:00:00 UTC");
+
+        // halfway rounds up
+        let dt = Utc.ymd(2012, 12, 12).and_hms(18, 22, 30);
+        assert_eq!(dt.duration_round(Duration::minutes(5)).unwrap().to_string(),
+                   "2012-12-12 18:25:00 UTC");
+
+        // rounding is done on the local time
+        let dt = FixedOffset::east(3600).ymd(2020, 10, 27).and_hms(15, 0, 0);
+        assert_eq!(dt.duration_round(Duration::days(1)).unwrap().to_string(),
+                   "2020-10-28 00:00:00 +01:00");
+        assert_eq!(dt.duration_trunc(Duration::days(1)).unwrap().to_string(),
+                   "2020-10-27 00:00:00 +01:00");
+    }
+
+    #[test]
+    fn test_duration_trunc_ceil() {
+        let dt = NaiveDate::from_ymd(2016, 12, 31).and_hms_nano(23, 59, 59, 175_500_000);
+        assert_eq!(dt.duration_trunc(Duration::milliseconds(10)).unwrap().to_string(),
+                   "2016-12-31 23:59:59.170");
+        assert_eq!(dt.duration_ceil(Duration::milliseconds(10)).unwrap().to_string(),
+                   "2016-12-31 23:59:59.180");
+        assert_eq!(dt.duration_trunc(Duration::minutes(15)).unwrap().to_string(),
+                   "2016-12-31 23:45:00");
+        assert_eq!(dt.duration_ceil(Duration::minutes(15)).unwrap().to_string(),
+                   "2017-01-01 00:00:00");
+
+        // exact multiples are left alone
+        let dt = NaiveDate::from_ymd(2016, 12, 31).and_hms(23, 45, 0);
+        assert_eq!(dt.duration_trunc(Duration::minutes(15)), Ok(dt));
+        assert_eq!(dt.duration_ceil(Duration::minutes(15)), Ok(dt));
+        assert_eq!(dt.duration_round(Duration::minutes(15)), Ok(dt));
+
+        // before the epoch
+        let dt = NaiveDate::from_ymd(1969, 12, 31).and_hms(23, 59, 30);
+        assert_eq!(dt.duration_trunc(Duration::minutes(1)).unwrap().to_string(),
+                   "1969-12-31 23:59:00");
+        assert_eq!(dt.duration_ceil(Duration::minutes(1)).unwrap().to_string(),
+                   "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn test_duration_round_errors() {
+        let dt = NaiveDate::from_ymd(2016, 12, 31).and_hms(23, 59, 59);
+        assert_eq!(dt.duration_round(Duration::zero()), Err(RoundingError::DurationNotPositive));
+        assert_eq!(dt.duration_trunc(Duration::seconds(-1)),
+                   Err(RoundingError::DurationNotPositive));
+        assert_eq!(dt.duration_ceil(Duration::days(365 * 300)),
+                   Err(RoundingError::DurationExceedsLimit));
+
+        let dt = NaiveDate::from_ymd(2300, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(dt.duration_round(Duration::seconds(1)),
+                   Err(RoundingError::TimestampExceedsLimit));
+    }
+}
@@ -0,0 +1,194 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The names of the [JavaScript Temporal API](https://tc39.es/proposal-temporal/docs/),
+//! mapped onto the Chrono types.
+//!
+//! This eases porting code between JavaScript and Rust.
+//! The types here are only aliases, so they have all the methods of the Chrono types
+//! and can be mixed with them freely.
+//!
+//! | Temporal                 | Chrono                    |
+//! |--------------------------|---------------------------|
+//! | `Temporal.PlainDate`     | `NaiveDate`               |
+//! | `Temporal.PlainTime`     | `NaiveTime`               |
+//! | `Temporal.PlainDateTime` | `NaiveDateTime`           |
+//! | `Temporal.ZonedDateTime` | `DateTime<Tz>`            |
+//! | `Temporal.Instant`       | `DateTime<Utc>`           |
+//! | `Temporal.Duration`      | `Duration`                |
+//!
+//! `Duration.prototype.round()` and `Duration.prototype.total()` are provided by
+//! the [`DurationExt`](./trait.DurationExt.html) trait. Unlike Temporal, the `Duration` has
+//! no calendar units; a day is always 86,400 seconds and a week is always seven days.
+//!
+//! ~~~~
+//! use chrono::{TimeZone, Utc};
+//! use chrono::temporal::{PlainDate, ZonedDateTime, Duration, DurationExt, Unit};
+//!
+//! let date = PlainDate::from_ymd(2020, 3, 14);
+//! let zoned: ZonedDateTime<Utc> = Utc.from_utc_date(&date).and_hms(1, 59, 26);
+//! assert_eq!(zoned.to_rfc3339(), "2020-03-14T01:59:26+00:00");
+//!
+//! let d = Duration::hours(1) + Duration::minutes(30);
+//! assert_eq!(d.total(Unit::Hour), 1.5);
+//! assert_eq!(d.round(Unit::Hour), Some(Duration::hours(2)));
+//! ~~~~
+
+use oldtime::Duration as OldDuration;
+
+use offset::Utc;
+
+pub use oldtime::Duration;
+
+/// A date without a time zone, as `Temporal.PlainDate`.
+pub type PlainDate = ::naive::NaiveDate;
+
+/// A time of the day without a time zone, as `Temporal.PlainTime`.
+pub type PlainTime = ::naive::NaiveTime;
+
+/// A date and time without a time zone, as `Temporal.PlainDateTime`.
+pub type PlainDateTime = ::naive::NaiveDateTime;
+
+/// A date and time in a time zone, as `Temporal.ZonedDateTime`.
+pub type ZonedDateTime<Tz> = ::DateTime<Tz>;
+
+/// An exact point in time, as `Temporal.Instant`.
+pub type Instant = ::DateTime<Utc>;
+
+/// The unit of time for [`DurationExt`](./trait.DurationExt.html).
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+pub enum Unit {
+    /// Seven days.
+    Week,
+    /// 86,400 seconds.
+    Day,
+    /// 3,600 seconds.
+    Hour,
+    /// 60 seconds.
+    Minute,
+    /// A second.
+    Second,
+    /// A thousandth of a second.
+    Millisecond,
+    /// A millionth of a second.
+    Microsecond,
+    /// A billionth of a second.
+    Nanosecond,
+}
+
+impl Unit {
+    /// Returns the length of the unit as seconds and nanoseconds, only one of which is non-zero.
+    fn length(&self) -> (i64, i64) {
+        match *self {
+            Unit::Week => (604_800, 0),
+            Unit::Day => (86_400, 0),
+            Unit::Hour => (3_600, 0),
+            Unit::Minute => (60, 0),
+            Unit::Second => (1, 0),
+            Unit::Millisecond => (0, 1_000_000),
+            Unit::Microsecond => (0, 1_000),
+            Unit::Nanosecond => (0, 1),
+        }
+    }
+}
+
+/// `Duration.prototype.round()` and `Duration.prototype.total()` of Temporal for `Duration`.
+pub trait DurationExt: Sized {
+    /// Rounds to the nearest multiple of `unit`, with halves rounded away from zero
+    /// (Temporal's default `halfExpand` rounding mode).
+    ///
+    /// Returns `None` when the result would be out of range.
+    fn round(&self, unit: Unit) -> Option<Self>;
+
+    /// Returns the length of the duration in given `unit`, with a fractional part.
+    fn total(&self, unit: Unit) -> f64;
+}
+
+impl DurationExt for OldDuration {
+    fn round(&self, unit: Unit) -> Option<OldDuration> {
+        // split into seconds and nanoseconds, both truncated towards zero
+        let secs = self.num_seconds();
+        let nanos = (*self - OldDuration::seconds(secs)).num_nanoseconds().unwrap();
+        let sign = if *self < OldDuration::zero() { -1 } else { 1 };
+
+        match unit.length() {
+            (unit_secs, 0) => {
+                let mut count = secs / unit_secs;
+                let rem = *self - OldDuration::seconds(count * unit_secs);
+                let rem = if rem < OldDuration::zero() { -rem } else { rem };
+                if rem * 2 >= OldDuration::seconds(unit_secs) {
+                    count += sign;
+                }
+                let secs = try_opt!(count.checked_mul(unit_secs));
+                if secs.abs() > OldDuration::max_value().num_seconds() {
+                    return None;
+                }
+                Some(OldDuration::seconds(secs))
+            }
+            (_, unit_nanos) => {
+                // the sub-second units divide a second, so only the nanoseconds are rounded
+                let mut count = nanos / unit_nanos;
+                if (nanos % unit_nanos).abs() * 2 >= unit_nanos {
+                    count += sign;
+                }
+                let rounded = OldDuration::nanoseconds(count * unit_nanos);
+                OldDuration::seconds(secs).checked_add(&rounded)
+            }
+        }
+    }
+
+    fn total(&self, unit: Unit) -> f64 {
+        let secs = self.num_seconds();
+        let nanos = (*self - OldDuration::seconds(secs)).num_nanoseconds().unwrap();
+        match unit.length() {
+            (unit_secs, 0) => (secs as f64 + nanos as f64 / 1e9) / unit_secs as f64,
+            (_, unit_nanos) => {
+                secs as f64 * (1_000_000_000 / unit_nanos) as f64 + nanos as f64 / unit_nanos as f64
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Duration, DurationExt, Unit};
+
+    #[test]
+    fn test_duration_round() {
+        let d = Duration::hours(1) + Duration::minutes(29) + Duration::milliseconds(59_999);
+        assert_eq!(d.round(Unit::Hour), Some(Duration::hours(1)));
+        assert_eq!((d + Duration::milliseconds(1)).round(Unit::Hour), Some(Duration::hours(2)));
+        assert_eq!((-d - Duration::milliseconds(1)).round(Unit::Hour), Some(Duration::hours(-2)));
+        assert_eq!(d.round(Unit::Minute), Some(Duration::minutes(90)));
+        assert_eq!(d.round(Unit::Second), Some(Duration::minutes(90)));
+        assert_eq!(d.round(Unit::Millisecond), Some(d));
+        assert_eq!(Duration::days(10).round(Unit::Week), Some(Duration::weeks(1)));
+        assert_eq!(Duration::days(-11).round(Unit::Week), Some(Duration::weeks(-2)));
+        assert_eq!(Duration::nanoseconds(1_500).round(Unit::Microsecond),
+                   Some(Duration::nanoseconds(2_000)));
+        assert_eq!(Duration::nanoseconds(-1_499).round(Unit::Microsecond),
+                   Some(Duration::nanoseconds(-1_000)));
+        assert_eq!(Duration::nanoseconds(999_499_999).round(Unit::Millisecond),
+                   Some(Duration::milliseconds(999)));
+        assert_eq!(Duration::nanoseconds(999_999_500).round(Unit::Microsecond),
+                   Some(Duration::seconds(1)));
+        assert_eq!(Duration::zero().round(Unit::Day), Some(Duration::zero()));
+        assert_eq!(Duration::max_value().round(Unit::Week), Some(Duration::weeks(15250284452)));
+        assert_eq!(Duration::max_value().round(Unit::Second), None);
+        assert_eq!(Duration::max_value().round(Unit::Millisecond), Some(Duration::max_value()));
+        assert_eq!(Duration::min_value().round(Unit::Second), None);
+    }
+
+    #[test]
+    fn test_duration_total() {
+        let d = Duration::hours(1) + Duration::minutes(30);
+        assert_eq!(d.total(Unit::Hour), 1.5);
+        assert_eq!(d.total(Unit::Minute), 90.0);
+        assert_eq!(d.total(Unit::Millisecond), 5_400_000.0);
+        assert_eq!((-d).total(Unit::Day), -0.0625);
+        assert_eq!(Duration::weeks(3).total(Unit::Week), 3.0);
+        assert_eq!(Duration::nanoseconds(1_500).total(Unit::Microsecond), 1.5);
+        assert_eq!(Duration::nanoseconds(-2_500_000_001).total(Unit::Nanosecond),
+                   -2_500_000_001.0);
+    }
+}
@@ -0,0 +1,608 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The time zones of the IANA time zone database (tzdb), like `America/New_York`.
+//!
+//! The time zone data is read from the compiled TZif files of the system,
+//! which are located in the directory given by the `TZDIR` environment variable
+//! or `/usr/share/zoneinfo` by default.
+//! The TZif files can be also supplied directly via
+//! [`Tz::from_tzif`](./struct.Tz.html#method.from_tzif) when the system has no time zone data.
+//!
+//! ~~~~ no_run
+//! use chrono::TimeZone;
+//! use chrono::tzdb::Tz;
+//!
+//! let new_york = Tz::named("America/New_York").unwrap();
+//! let dt = new_york.ymd(2019, 7, 1).and_hms(12, 0, 0);
+//! assert_eq!(dt.to_string(), "2019-07-01 12:00:00 EDT");
+//! assert_eq!(dt.offset().to_string(), "EDT");
+//! ~~~~
+
+use std::{env, fmt, fs, io};
+use std::error::Error;
+use std::path::{Path, PathBuf, Component};
+use std::sync::Arc;
+
+use naive::{NaiveDate, NaiveDateTime};
+use offset::{TimeZone, Offset, LocalResult, FixedOffset, Utc};
+use DateTime;
+
+mod rule;
+mod tzif;
+
+use self::rule::Rule;
+use self::tzif::LocalTimeType;
+
+/// An error from loading the time zone.
+#[derive(Debug)]
+pub enum TzError {
+    /// The time zone file cannot be read.
+    Io(io::Error),
+    /// The time zone name is not a valid relative path like `Europe/Paris`.
+    InvalidName,
+    /// The time zone file is not a valid TZif file.
+    InvalidTzif,
+}
+
+impl fmt::Display for TzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TzError::Io(ref err) => write!(f, "cannot read the time zone file: {}", err),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for TzError {
+    fn description(&self) -> &str {
+        match *self {
+            TzError::Io(..) => "cannot read the time zone file",
+            TzError::InvalidName => "invalid time zone name",
+            TzError::InvalidTzif => "invalid time zone file",
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            TzError::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TzError {
+    fn from(err: io::Error) -> TzError {
+        TzError::Io(err)
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct ZoneData {
+    name: String,
+    /// The transition times as UNIX timestamps, paired with the index to `types`.
+    transitions: Vec<(i64, usize)>,
+    /// The local time types, including those from the rule.
+    types: Vec<LocalTimeType>,
+    /// The rule after the last transition, with the indices of its standard and DST types.
+    rule: Option<(Rule, usize, Option<usize>)>,
+}
+
+/// A time zone from the IANA time zone database.
+///
+/// `Tz` is cheap to clone, since the time zone data is shared by reference counting.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Tz {
+    inner: Arc<ZoneData>,
+}
+
+impl Tz {
+    /// Loads the time zone of given name, like `America/New_York`, from the system tzdata.
+    ///
+    /// The directory of the tzdata is taken from the `TZDIR` environment variable,
+    /// or `/usr/share/zoneinfo` if it's not set.
+    /// The name should be a relative path without `..` components.
+    pub fn named(name: &str) -> Result<Tz, TzError> {
+        let relative = Path::new(name);
+        if name.is_empty() || relative.components().any(|c| match c {
+            Component::Normal(_) => false,
+            _ => true,
+        }) {
+            return Err(TzError::InvalidName);
+        }
+        let dir = env::var_os("TZDIR").map_or_else(|| PathBuf::from("/usr/share/zoneinfo"),
+                                                   PathBuf::from);
+        let bytes = read_file(&dir.join(relative))?;
+        Tz::from_tzif(name, &bytes)
+    }
+
+    /// Loads the time zone from given TZif file.
+    /// The name of the time zone is the path as given.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Tz, TzError> {
+        let path = path.as_ref();
+        let bytes = read_file(path)?;
+        Tz::from_tzif(&path.to_string_lossy(), &bytes)
+    }
+
+    /// Makes a new time zone of given name from the contents of the TZif file.
+    pub fn from_tzif(name: &str, bytes: &[u8]) -> Result<Tz, TzError> {
+        let data = tzif::parse(bytes).ok_or(TzError::InvalidTzif)?;
+        let mut types = data.types;
+        let rule = match data.footer {
+            Some(footer) => {
+                let rule = Rule::parse(&footer).ok_or(TzError::InvalidTzif)?;
+                let std = rule_type_index(&mut types, rule.std())?;
+                let dst = match rule.dst() {
+                    Some(dst) => Some(rule_type_index(&mut types, dst)?),
+                    None => None,
+                };
+                Some((rule, std, dst))
+            }
+            None => None,
+        };
+        let data = ZoneData { name: name.to_owned(), transitions: data.transitions,
+                              types: types, rule: rule };
+        Ok(Tz { inner: Arc::new(data) })
+    }
+
+    /// Returns the name of the time zone.
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Returns the first transition of this time zone strictly after given instant,
+    /// or `None` if the offset never changes afterwards.
+    ///
+    /// Only the transitions which actually change the offset, its abbreviation
+    /// or its DST flag are returned.
+    ///
+    /// # Example
+    ///
+    /// ~~~~ no_run
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono::tzdb::Tz;
+    ///
+    /// let tz = Tz::named("America/New_York").unwrap();
+    /// let t = tz.next_transition(&Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)).unwrap();
+    /// assert_eq!(t.instant(), Utc.ymd(2019, 3, 10).and_hms(7, 0, 0));
+    /// assert_eq!((t.before().abbreviation(), t.after().abbreviation()), ("EST", "EDT"));
+    /// assert!(t.is_gap());
+    /// ~~~~
+    pub fn next_transition<Tz2: TimeZone>(&self, after: &DateTime<Tz2>) -> Option<Transition> {
+        let t = try_opt!(self.next_change(after.timestamp()));
+        self.transition(t)
+    }
+
+    /// Returns the last transition of this time zone strictly before given instant,
+    /// or `None` if the offset has never changed before.
+    ///
+    /// Only the transitions which actually change the offset, its abbreviation
+    /// or its DST flag are returned.
+    pub fn previous_transition<Tz2: TimeZone>(&self, before: &DateTime<Tz2>)
+                                              -> Option<Transition> {
+        // a transition at the same second precedes the instant with a fractional second
+        let mut timestamp = before.timestamp();
+        if before.timestamp_subsec_nanos() > 0 {
+            timestamp += 1;
+        }
+        let t = try_opt!(self.prev_change(timestamp));
+        self.transition(t)
+    }
+
+    fn transition(&self, timestamp: i64) -> Option<Transition> {
+        let instant = try_opt!(Utc.timestamp_opt(timestamp, 0).single());
+        Some(Transition {
+            instant: instant,
+            before: self.offset(self.type_at(timestamp.saturating_sub(1))),
+            after: self.offset(self.type_at(timestamp)),
+        })
+    }
+
+    /// Returns the index of the local time type in effect at given UNIX timestamp.
+    fn type_at(&self, timestamp: i64) -> usize {
+        let zone = &*self.inner;
+        let idx = count_until(&zone.transitions, timestamp, true);
+        match zone.rule {
+            Some((ref rule, std, dst)) if idx == zone.transitions.len() => {
+                match dst {
+                    Some(dst) if rule.is_dst_at(timestamp) => dst,
+                    _ => std,
+                }
+            }
+            _ if idx == 0 => 0,
+            _ => zone.transitions[idx - 1].1,
+        }
+    }
+
+    /// Returns the first transition strictly after given UNIX timestamp,
+    /// which may not actually change the local time type.
+    fn raw_next_transition(&self, timestamp: i64) -> Option<i64> {
+        let zone = &*self.inner;
+        let idx = count_until(&zone.transitions, timestamp, true);
+        if idx < zone.transitions.len() {
+            return Some(zone.transitions[idx].0);
+        }
+        zone.rule.as_ref().and_then(|&(ref rule, _, _)| rule.next_transition(timestamp))
+                          .map(|(t, _)| t)
+    }
+
+    /// Returns the last transition strictly before given UNIX timestamp,
+    /// which may not actually change the local time type.
+    fn raw_prev_transition(&self, timestamp: i64) -> Option<i64> {
+        let zone = &*self.inner;
+        let idx = count_until(&zone.transitions, timestamp, false);
+        let last = if idx > 0 { Some(zone.transitions[idx - 1].0) } else { None };
+        if idx == zone.transitions.len() {
+            if let Some((ref rule, _, _)) = zone.rule {
+                if let Some((t, _)) = rule.prev_transition(timestamp) {
+                    if last.map_or(true, |last| t > last) {
+                        return Some(t);
+                    }
+                }
+            }
+        }
+        last
+    }
+
+    /// Returns the first instant strictly after given UNIX timestamp
+    /// when the local time type changes.
+    fn next_change(&self, timestamp: i64) -> Option<i64> {
+        let mut t = timestamp;
+        loop {
+            t = try_opt!(self.raw_next_transition(t));
+            if self.changes_at(t) {
+                return Some(t);
+            }
+        }
+    }
+
+    /// Returns the last instant strictly before given UNIX timestamp
+    /// when the local time type changes.
+    fn prev_change(&self, timestamp: i64) -> Option<i64> {
+        let mut t = timestamp;
+        loop {
+            t = try_opt!(self.raw_prev_transition(t));
+            if self.changes_at(t) {
+                return Some(t);
+            }
+        }
+    }
+
+    fn changes_at(&self, timestamp: i64) -> bool {
+        let types = &self.inner.types;
+        // the transition times come from the file, which may contain `i64::MIN`
+        types[self.type_at(timestamp.saturating_sub(1))] != types[self.type_at(timestamp)]
+    }
+
+    fn offset(&self, index: usize) -> TzOffset {
+        TzOffset { zone: self.clone(), index: index }
+    }
+}
+
+/// A transition of the time zone, where the offset changes at the particular instant.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Transition {
+    instant: DateTime<Utc>,
+    before: TzOffset,
+    after: TzOffset,
+}
+
+impl Transition {
+    /// Returns the instant of the transition, from which the new offset is in effect.
+    pub fn instant(&self) -> DateTime<Utc> {
+        self.instant
+    }
+
+    /// Returns the offset in effect right before the transition.
+    pub fn before(&self) -> &TzOffset {
+        &self.before
+    }
+
+    /// Returns the offset in effect from the transition.
+    pub fn after(&self) -> &TzOffset {
+        &self.after
+    }
+
+    /// Returns the local date and time right at the transition, in the old offset.
+    /// The local time before the transition is strictly less than this.
+    pub fn local_before(&self) -> NaiveDateTime {
+        self.instant.naive_utc() + self.before.fix()
+    }
+
+    /// Returns the local date and time right at the transition, in the new offset.
+    pub fn local_after(&self) -> NaiveDateTime {
+        self.instant.naive_utc() + self.after.fix()
+    }
+
+    /// Returns `true` if the local time jumps forward, skipping the local times
+    /// from `local_before()` until `local_after()` (exclusive) which do not exist.
+    pub fn is_gap(&self) -> bool {
+        self.after.fix().local_minus_utc() > self.before.fix().local_minus_utc()
+    }
+
+    /// Returns `true` if the local time jumps backward, repeating the local times
+    /// from `local_after()` until `local_before()` (exclusive) which become ambiguous.
+    pub fn is_fold(&self) -> bool {
+        self.after.fix().local_minus_utc() < self.before.fix().local_minus_utc()
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, TzError> {
+    use std::io::Read;
+    let mut bytes = Vec::new();
+    fs::File::open(path)?.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Returns the number of transitions before (or at, if `inclusive`) given UNIX timestamp.
+fn count_until(transitions: &[(i64, usize)], timestamp: i64, inclusive: bool) -> usize {
+    match transitions.binary_search_by(|&(t, _)| t.cmp(&timestamp)) {
+        Ok(i) if inclusive => i + 1,
+        Ok(i) | Err(i) => i,
+    }
+}
+
+/// Finds or adds the local time type from the rule, returning its index.
+fn rule_type_index(types: &mut Vec<LocalTimeType>, t: &rule::RuleType) -> Result<usize, TzError> {
+    if t.utoff <= -86_400 || t.utoff >= 86_400 {
+        return Err(TzError::InvalidTzif);
+    }
+    let t = LocalTimeType { utoff: t.utoff, is_dst: t.is_dst, abbr: t.abbr.clone() };
+    if let Some(i) = types.iter().position(|u| *u == t) {
+        return Ok(i);
+    }
+    types.push(t);
+    Ok(types.len() - 1)
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.zone.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        const DAY: i64 = 86_400;
+        let local = local.timestamp();
+        let types = &self.inner.types;
+
+        // every offset is less than a day, so the candidates are in effect
+        // somewhere within a day from the local time interpreted as UTC
+        let mut t = local - DAY;
+        let mut candidates = vec![self.type_at(t)];
+        while let Some(next) = self.next_change(t) {
+            if next >= local + DAY {
+                break;
+            }
+            candidates.push(self.type_at(next));
+            t = next;
+        }
+
+        let mut found: Vec<usize> = Vec::new();
+        for &c in &candidates {
+            let idx = self.type_at(local - types[c].utoff as i64);
+            if types[idx].utoff == types[c].utoff && !found.contains(&idx) {
+                found.push(idx);
+            }
+        }
+        match found.len() {
+            0 => LocalResult::None,
+            1 => LocalResult::Single(self.offset(found[0])),
+            n => LocalResult::Ambiguous(self.offset(found[0]), self.offset(found[n - 1])),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+    }
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        self.offset(self.type_at(utc.timestamp()))
+    }
+}
+
+impl fmt::Debug for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner.name)
+    }
+}
+
+impl fmt::Display for Tz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner.name)
+    }
+}
+
+/// The offset from UTC in the time zone from the IANA time zone database,
+/// along with its abbreviation and whether it's a daylight saving time.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TzOffset {
+    zone: Tz,
+    index: usize,
+}
+
+impl TzOffset {
+    fn local_time_type(&self) -> &LocalTimeType {
+        &self.zone.inner.types[self.index]
+    }
+
+    /// Returns the abbreviation of this offset, like `EST` or `+0330`.
+    pub fn abbreviation(&self) -> &str {
+        &self.local_time_type().abbr
+    }
+
+    /// Returns `true` if this offset is a daylight saving time.
+    pub fn is_dst(&self) -> bool {
+        self.local_time_type().is_dst
+    }
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east(self.local_time_type().utoff)
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::i64;
+    use std::io::{self, Write};
+    use std::path::Path;
+
+    use naive::NaiveDate;
+    use offset::{TimeZone, Offset, LocalResult, Utc};
+    use Timelike;
+    use super::{Tz, TzError};
+    use super::tzif::tests::build;
+
+    fn ts(y: i32, m: u32, d: u32, h: u32) -> i64 {
+        NaiveDate::from_ymd(y, m, d).and_hms(h, 0, 0).timestamp()
+    }
+
+    /// A time zone like `America/New_York` with the transitions only in 2018.
+    fn new_york() -> Tz {
+        let bytes = build(&[(ts(2018, 3, 11, 7), 1), (ts(2018, 11, 4, 6), 0)],
+                          &[(-18_000, false, "EST"), (-14_400, true, "EDT")],
+                          "EST5EDT,M3.2.0,M11.1.0");
+        Tz::from_tzif("Test/New_York", &bytes).unwrap()
+    }
+
+    #[test]
+    fn test_tz_utc_to_local() {
+        let tz = new_york();
+        assert_eq!(tz.name(), "Test/New_York");
+        for &((y, m, d, h), abbr, utoff) in &[((2000, 1, 1, 0), "EST", -5),
+                                              ((2018, 3, 11, 6), "EST", -5),
+                                              ((2018, 3, 11, 7), "EDT", -4),
+                                              ((2018, 11, 4, 6), "EST", -5),
+                                              ((2019, 7, 1, 0), "EDT", -4),
+                                              ((2019, 11, 3, 6), "EST", -5),
+                                              ((2100, 7, 1, 0), "EDT", -4)] {
+            let dt = tz.timestamp(ts(y, m, d, h), 0);
+            assert_eq!(dt.offset().abbreviation(), abbr);
+            assert_eq!(dt.offset().is_dst(), abbr == "EDT");
+            assert_eq!(dt.offset().fix().local_minus_utc(), utoff * 3600);
+        }
+        assert_eq!(tz.ymd(2019, 7, 1).and_hms(12, 0, 0).to_string(), "2019-07-01 12:00:00 EDT");
+    }
+
+    #[test]
+    fn test_tz_local_to_utc() {
+        let tz = new_york();
+        let local = |y, m, d, h, mi| NaiveDate::from_ymd(y, m, d).and_hms(h, mi, 0);
+        for &year in &[2018, 2019] {
+            let (spring, fall) = if year == 2018 { (11, 4) } else { (10, 3) };
+            assert_eq!(tz.from_local_datetime(&local(year, 3, spring, 2, 30)), LocalResult::None);
+            match tz.from_local_datetime(&local(year, 11, fall, 1, 30)) {
+                LocalResult::Ambiguous(a, b) => {
+                    assert_eq!(a.offset().abbreviation(), "EDT");
+                    assert_eq!(b.offset().abbreviation(), "EST");
+                    assert_eq!(b.timestamp() - a.timestamp(), 3600);
+                }
+                r => panic!("unexpected {:?}", r),
+            }
+            let dt = tz.from_local_datetime(&local(year, 7, 1, 12, 0)).unwrap();
+            assert_eq!(dt.timestamp(), ts(year, 7, 1, 16));
+        }
+    }
+
+    #[test]
+    fn test_tz_transitions() {
+        let tz = new_york();
+        let utc = |y, m, d, h| Utc.ymd(y, m, d).and_hms(h, 0, 0);
+        let local = |y, m, d, h| NaiveDate::from_ymd(y, m, d).and_hms(h, 0, 0);
+
+        let t = tz.next_transition(&utc(2018, 1, 1, 0)).unwrap();
+        assert_eq!(t.instant(), utc(2018, 3, 11, 7));
+        assert_eq!((t.before().abbreviation(), t.after().abbreviation()), ("EST", "EDT"));
+        assert_eq!((t.local_before(), t.local_after()),
+                   (local(2018, 3, 11, 2), local(2018, 3, 11, 3)));
+        assert!(t.is_gap() && !t.is_fold());
+
+        // the given instant itself is excluded
+        let t = tz.next_transition(&t.instant().with_timezone(&tz)).unwrap();
+        assert_eq!(t.instant(), utc(2018, 11, 4, 6));
+        assert_eq!((t.local_before(), t.local_after()),
+                   (local(2018, 11, 4, 2), local(2018, 11, 4, 1)));
+        assert!(t.is_fold() && !t.is_gap());
+
+        // the transitions after the last one in the file come from the rule
+        let t = tz.next_transition(&utc(2018, 11, 4, 6)).unwrap();
+        assert_eq!(t.instant(), utc(2019, 3, 10, 7));
+        let t = tz.previous_transition(&utc(2019, 3, 10, 7)).unwrap();
+        assert_eq!(t.instant(), utc(2018, 11, 4, 6));
+        let t = tz.previous_transition(&utc(2019, 3, 10, 7).with_nanosecond(1).unwrap()).unwrap();
+        assert_eq!(t.instant(), utc(2019, 3, 10, 7));
+        let t = tz.previous_transition(&utc(2025, 1, 1, 0)).unwrap();
+        assert_eq!(t.instant(), utc(2024, 11, 3, 6));
+        assert_eq!(tz.previous_transition(&utc(2018, 3, 11, 7)), None);
+
+        // no-op transitions are skipped
+        let bytes = build(&[(0, 1), (100, 0)], &[(0, false, "UTC"), (0, false, "UTC")], "");
+        let tz = Tz::from_tzif("Test/UTC", &bytes).unwrap();
+        assert_eq!(tz.next_transition(&utc(1960, 1, 1, 0)), None);
+        assert_eq!(tz.previous_transition(&utc(2000, 1, 1, 0)), None);
+
+        // a transition at the earliest representable time is never reported
+        let bytes = build(&[(i64::MIN, 1)], &[(0, false, "LMT"), (3600, false, "CET")], "");
+        let tz = Tz::from_tzif("Test/Min", &bytes).unwrap();
+        assert_eq!(tz.previous_transition(&utc(2000, 1, 1, 0)), None);
+    }
+
+    #[test]
+    fn test_tz_dst_all_year() {
+        // the rule transitions are no-ops, and should not be searched until the end of time
+        let bytes = build(&[(0, 0)], &[(-14_400, true, "EDT")], "EST5EDT,0/0,J365/25");
+        let tz = Tz::from_tzif("Test/EDT", &bytes).unwrap();
+        let local = NaiveDate::from_ymd(2019, 7, 1).and_hms(12, 0, 0);
+        let dt = tz.from_local_datetime(&local).unwrap();
+        assert_eq!(dt.offset().abbreviation(), "EDT");
+        assert_eq!(dt.timestamp(), ts(2019, 7, 1, 16));
+        assert_eq!(tz.next_transition(&Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)), None);
+        assert_eq!(tz.previous_transition(&Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_tz_invalid() {
+        assert!(match Tz::from_tzif("X", b"TZif") {
+            Err(TzError::InvalidTzif) => true,
+            _ => false,
+        });
+        let bytes = build(&[], &[(0, false, "UTC")], "UTC0x");
+        assert!(Tz::from_tzif("X", &bytes).is_err());
+        for &name in &["", "../etc/passwd", "/etc/passwd", "America/../../etc/passwd"] {
+            assert!(match Tz::named(name) { Err(TzError::InvalidName) => true, _ => false },
+                    "{:?} should be rejected", name);
+        }
+    }
+
+    #[test]
+    fn test_tz_named() {
+        // the system tzdata may not be available
+        if !Path::new("/usr/share/zoneinfo/America/New_York").exists() {
+            let _ = writeln!(io::stderr(), "skipping test_tz_named: no system tzdata");
+            return;
+        }
+        let tz = Tz::from_file("/usr/share/zoneinfo/America/New_York").unwrap();
+        let dt = tz.ymd(2019, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(dt.offset().abbreviation(), "EST");
+        assert_eq!(dt.with_timezone(&tz).timestamp(), ts(2019, 1, 1, 5));
+        let dt = tz.ymd(1883, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(dt.offset().abbreviation(), "LMT");
+    }
+}
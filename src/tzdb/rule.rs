@@ -0,0 +1,354 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! POSIX `TZ` strings, used in the footer of TZif files for the instants after
+//! the last transition (RFC 8536, section 3.3).
+
+use naive::{NaiveDate, NaiveDateTime, MIN_DATE, MAX_DATE};
+use Datelike;
+
+/// A local time type described by a POSIX `TZ` string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleType {
+    pub utoff: i32,
+    pub is_dst: bool,
+    pub abbr: String,
+}
+
+/// A date in the year when the daylight saving time starts or ends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: the Julian day from 1 to 365, never counting February 29.
+    Julian1(u32),
+    /// `n`: the zero-based Julian day from 0 to 365, counting February 29.
+    Julian0(u32),
+    /// `Mm.w.d`: the day `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay(u32, u32, u32),
+}
+
+impl RuleDate {
+    fn to_naive_date(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            RuleDate::Julian1(n) => {
+                let jan1 = try_opt!(NaiveDate::from_ymd_opt(year, 1, 1));
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = if leap && n >= 60 { n + 1 } else { n };
+                jan1.with_ordinal(ordinal)
+            }
+            RuleDate::Julian0(n) => NaiveDate::from_yo_opt(year, n + 1),
+            RuleDate::MonthWeekDay(m, w, d) => {
+                let first = try_opt!(NaiveDate::from_ymd_opt(year, m, 1));
+                let skip = (d + 7 - first.weekday().num_days_from_sunday()) % 7;
+                let mut day = 1 + skip + (w - 1) * 7;
+                while NaiveDate::from_ymd_opt(year, m, day).is_none() {
+                    day -= 7; // the fifth week means the last one
+                }
+                NaiveDate::from_ymd_opt(year, m, day)
+            }
+        }
+    }
+}
+
+/// The daylight saving time part of a POSIX `TZ` string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct DstRule {
+    dst: RuleType,
+    start: RuleDate,
+    start_time: i32,
+    end: RuleDate,
+    end_time: i32,
+}
+
+/// A parsed POSIX `TZ` string like `EST5EDT,M3.2.0,M11.1.0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    std: RuleType,
+    dst: Option<DstRule>,
+    /// Whether any transition actually switches between the standard and daylight saving time.
+    /// This is `false` for the daylight saving time all year (RFC 8536, section 3.3.1).
+    effective: bool,
+}
+
+impl Rule {
+    /// Parses a POSIX `TZ` string, with the extensions of RFC 8536.
+    pub fn parse(s: &str) -> Option<Rule> {
+        let mut p = Parser { s: s.as_bytes(), pos: 0 };
+        let mut rule = try_opt!(p.rule());
+        if p.pos != p.s.len() {
+            return None;
+        }
+        rule.effective = rule.has_effective_transition();
+        Some(rule)
+    }
+
+    /// Returns whether any transition switches between the standard and daylight saving time.
+    ///
+    /// The rule repeats every 400 years of the Gregorian calendar, so one cycle is checked.
+    fn has_effective_transition(&self) -> bool {
+        (2000..2400).any(|year| {
+            self.transitions_in_year(year).iter().any(|&(t, _)| {
+                self.is_dst_at(t - 1) != self.is_dst_at(t)
+            })
+        })
+    }
+
+    /// Returns the standard time type.
+    pub fn std(&self) -> &RuleType {
+        &self.std
+    }
+
+    /// Returns the daylight saving time type, if any.
+    pub fn dst(&self) -> Option<&RuleType> {
+        self.dst.as_ref().map(|d| &d.dst)
+    }
+
+    /// Returns the transitions in given year, as pairs of UNIX timestamp and
+    /// whether the daylight saving time is in effect after the transition.
+    fn transitions_in_year(&self, year: i32) -> Vec<(i64, bool)> {
+        let d = match self.dst {
+            Some(ref d) => d,
+            None => return Vec::new(),
+        };
+        let mut ret = Vec::with_capacity(2);
+        if let Some(date) = d.start.to_naive_date(year) {
+            let local = date.and_hms(0, 0, 0).timestamp() + d.start_time as i64;
+            ret.push((local - self.std.utoff as i64, true));
+        }
+        if let Some(date) = d.end.to_naive_date(year) {
+            let local = date.and_hms(0, 0, 0).timestamp() + d.end_time as i64;
+            ret.push((local - d.dst.utoff as i64, false));
+        }
+        ret
+    }
+
+    /// Returns the transitions around the year of given timestamp, sorted.
+    fn transitions_around(&self, timestamp: i64) -> Vec<(i64, bool)> {
+        let year = year_of(timestamp);
+        let mut ret = Vec::with_capacity(6);
+        for y in year - 1..year + 2 {
+            ret.extend(self.transitions_in_year(y));
+        }
+        ret.sort();
+        ret
+    }
+
+    /// Returns whether the daylight saving time is in effect at given timestamp.
+    pub fn is_dst_at(&self, timestamp: i64) -> bool {
+        self.transitions_around(timestamp).iter().rev()
+            .find(|&&(t, _)| t <= timestamp).map_or(false, |&(_, dst)| dst)
+    }
+
+    /// Returns the first transition strictly after given timestamp.
+    pub fn next_transition(&self, timestamp: i64) -> Option<(i64, bool)> {
+        if !self.effective {
+            return None;
+        }
+        self.transitions_around(timestamp).into_iter().find(|&(t, _)| t > timestamp)
+    }
+
+    /// Returns the last transition strictly before given timestamp.
+    pub fn prev_transition(&self, timestamp: i64) -> Option<(i64, bool)> {
+        if !self.effective {
+            return None;
+        }
+        self.transitions_around(timestamp).into_iter().rev().find(|&(t, _)| t < timestamp)
+    }
+}
+
+/// Returns the year of given UNIX timestamp, clamped to the supported range.
+fn year_of(timestamp: i64) -> i32 {
+    match NaiveDateTime::from_timestamp_opt(timestamp, 0) {
+        Some(dt) => dt.year(),
+        None if timestamp < 0 => MIN_DATE.year(),
+        None => MAX_DATE.year(),
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).cloned()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self, max: u32) -> Option<u32> {
+        let start = self.pos;
+        let mut n = 0u32;
+        while let Some(c @ b'0'...b'9') = self.peek() {
+            n = try_opt!(n.checked_mul(10)).saturating_add((c - b'0') as u32);
+            self.pos += 1;
+        }
+        if self.pos == start || n > max { None } else { Some(n) }
+    }
+
+    fn abbr(&mut self) -> Option<String> {
+        let start;
+        let end;
+        if self.eat(b'<') {
+            start = self.pos;
+            while let Some(c) = self.peek() {
+                match c {
+                    b'A'...b'Z' | b'a'...b'z' | b'0'...b'9' | b'+' | b'-' => self.pos += 1,
+                    _ => break,
+                }
+            }
+            end = self.pos;
+            if !self.eat(b'>') { return None; }
+        } else {
+            start = self.pos;
+            while let Some(c) = self.peek() {
+                match c {
+                    b'A'...b'Z' | b'a'...b'z' => self.pos += 1,
+                    _ => break,
+                }
+            }
+            end = self.pos;
+        }
+        if end - start < 3 { return None; }
+        String::from_utf8(self.s[start..end].to_vec()).ok()
+    }
+
+    /// Parses `[+-]hh[:mm[:ss]]` into seconds.
+    fn hms(&mut self, max_hours: u32) -> Option<i32> {
+        let sign = if self.eat(b'-') { -1 } else { self.eat(b'+'); 1 };
+        let mut secs = try_opt!(self.number(max_hours)) as i32 * 3600;
+        if self.eat(b':') {
+            secs += try_opt!(self.number(59)) as i32 * 60;
+            if self.eat(b':') {
+                secs += try_opt!(self.number(59)) as i32;
+            }
+        }
+        Some(sign * secs)
+    }
+
+    fn date(&mut self) -> Option<RuleDate> {
+        if self.eat(b'J') {
+            let n = try_opt!(self.number(365));
+            if n == 0 { None } else { Some(RuleDate::Julian1(n)) }
+        } else if self.eat(b'M') {
+            let m = try_opt!(self.number(12));
+            if !self.eat(b'.') { return None; }
+            let w = try_opt!(self.number(5));
+            if !self.eat(b'.') { return None; }
+            let d = try_opt!(self.number(6));
+            if m == 0 || w == 0 { None } else { Some(RuleDate::MonthWeekDay(m, w, d)) }
+        } else {
+            Some(RuleDate::Julian0(try_opt!(self.number(365))))
+        }
+    }
+
+    fn date_time(&mut self) -> Option<(RuleDate, i32)> {
+        let date = try_opt!(self.date());
+        let time = if self.eat(b'/') { try_opt!(self.hms(167)) } else { 7200 };
+        Some((date, time))
+    }
+
+    fn rule(&mut self) -> Option<Rule> {
+        let std_abbr = try_opt!(self.abbr());
+        // POSIX offsets are positive to the west of Greenwich
+        let std_utoff = -try_opt!(self.hms(24));
+        let std = RuleType { utoff: std_utoff, is_dst: false, abbr: std_abbr };
+        if self.peek().is_none() {
+            return Some(Rule { std: std, dst: None, effective: false });
+        }
+
+        let dst_abbr = try_opt!(self.abbr());
+        let dst_utoff = match self.peek() {
+            None | Some(b',') => std_utoff + 3600,
+            _ => -try_opt!(self.hms(24)),
+        };
+        let dst = RuleType { utoff: dst_utoff, is_dst: true, abbr: dst_abbr };
+        let ((start, start_time), (end, end_time)) = if self.eat(b',') {
+            let start = try_opt!(self.date_time());
+            if !self.eat(b',') { return None; }
+            (start, try_opt!(self.date_time()))
+        } else {
+            // the default rule of POSIX, which follows the United States
+            ((RuleDate::MonthWeekDay(3, 2, 0), 7200), (RuleDate::MonthWeekDay(11, 1, 0), 7200))
+        };
+        Some(Rule {
+            std: std,
+            dst: Some(DstRule { dst: dst, start: start, start_time: start_time,
+                                end: end, end_time: end_time }),
+            effective: false, // computed by `Rule::parse`
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use naive::NaiveDate;
+    use super::Rule;
+
+    #[test]
+    fn test_rule_parse() {
+        let rule = Rule::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(rule.std().utoff, -5 * 3600);
+        assert_eq!(rule.std().abbr, "EST");
+        assert_eq!(rule.dst().unwrap().utoff, -4 * 3600);
+        assert_eq!(rule.dst().unwrap().abbr, "EDT");
+
+        let rule = Rule::parse("<+0330>-3:30").unwrap();
+        assert_eq!(rule.std().utoff, 3 * 3600 + 30 * 60);
+        assert_eq!(rule.std().abbr, "+0330");
+        assert!(rule.dst().is_none());
+
+        let rule = Rule::parse("IST-1GMT0,M10.5.0,M3.5.0/1").unwrap();
+        assert_eq!(rule.std().utoff, 3600);
+        assert_eq!(rule.dst().unwrap().utoff, 0);
+
+        assert!(Rule::parse("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1").is_some());
+        assert!(Rule::parse("EST5EDT,J60/25,300").is_some());
+
+        for s in &["", "E5", "EST", "EST5EDT,M3.2.0", "EST5EDT,M13.2.0,M11.1.0", "EST5x",
+                   "<EST5", "EST25"] {
+            assert_eq!(Rule::parse(s), None, "{:?} should be rejected", s);
+        }
+    }
+
+    #[test]
+    fn test_rule_transitions() {
+        let ts = |y, m, d, h| NaiveDate::from_ymd(y, m, d).and_hms(h, 0, 0).timestamp();
+        let rule = Rule::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        // March 10, 2019 02:00 EST and November 3, 2019 02:00 EDT
+        assert_eq!(rule.next_transition(ts(2019, 1, 1, 0)), Some((ts(2019, 3, 10, 7), true)));
+        assert_eq!(rule.next_transition(ts(2019, 3, 10, 7)), Some((ts(2019, 11, 3, 6), false)));
+        assert_eq!(rule.prev_transition(ts(2019, 3, 10, 7)), Some((ts(2018, 11, 4, 6), false)));
+        assert!(!rule.is_dst_at(ts(2019, 3, 10, 7) - 1));
+        assert!(rule.is_dst_at(ts(2019, 3, 10, 7)));
+        assert!(!rule.is_dst_at(ts(2019, 11, 3, 6)));
+
+        // the southern hemisphere, where the daylight saving time spans the new year
+        let rule = Rule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert!(rule.is_dst_at(ts(2019, 1, 1, 0)));
+        assert!(!rule.is_dst_at(ts(2019, 7, 1, 0)));
+        // April 7, 2019 03:00 AEDT and October 6, 2019 02:00 AEST
+        assert_eq!(rule.next_transition(ts(2019, 1, 1, 0)), Some((ts(2019, 4, 6, 16), false)));
+        assert_eq!(rule.next_transition(ts(2019, 7, 1, 0)), Some((ts(2019, 10, 5, 16), true)));
+
+        // the fifth week means the last one, and `Jn` never counts February 29
+        let rule = Rule::parse("XST0XDT,M2.5.0/0,J60/0").unwrap();
+        assert_eq!(rule.next_transition(ts(2020, 1, 1, 0)), Some((ts(2020, 2, 23, 0), true)));
+        assert_eq!(rule.next_transition(ts(2020, 2, 23, 0)), Some((ts(2020, 2, 29, 23), false)));
+
+        // the daylight saving time all year has no transitions
+        let rule = Rule::parse("EST5EDT,0/0,J365/25").unwrap();
+        assert!(rule.is_dst_at(ts(2019, 1, 1, 5)));
+        assert!(rule.is_dst_at(ts(2019, 7, 1, 0)));
+        assert!(rule.is_dst_at(ts(2019, 12, 31, 23)));
+        assert_eq!(rule.next_transition(ts(2019, 1, 1, 0)), None);
+        assert_eq!(rule.prev_transition(ts(2019, 1, 1, 0)), None);
+    }
+}
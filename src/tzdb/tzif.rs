@@ -0,0 +1,266 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The Time Zone Information Format (TZif), as described in RFC 8536.
+
+/// A local time type in the TZif file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalTimeType {
+    pub utoff: i32,
+    pub is_dst: bool,
+    pub abbr: String,
+}
+
+/// The parsed contents of the TZif file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TzifData {
+    /// The transition times as UNIX timestamps, paired with the index to `types`.
+    pub transitions: Vec<(i64, usize)>,
+    /// The local time types. The first one is used before the first transition.
+    pub types: Vec<LocalTimeType>,
+    /// The POSIX `TZ` string for the instants after the last transition, if any.
+    pub footer: Option<String>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = try_opt!(self.pos.checked_add(n));
+        if end > self.bytes.len() {
+            return None;
+        }
+        let ret = &self.bytes[self.pos..end];
+        self.pos = end;
+        Some(ret)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4).map(|b| {
+            (b[0] as u32) << 24 | (b[1] as u32) << 16 | (b[2] as u32) << 8 | b[3] as u32
+        })
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.u32().map(|v| v as i32)
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        let hi = try_opt!(self.u32()) as u64;
+        let lo = try_opt!(self.u32()) as u64;
+        Some((hi << 32 | lo) as i64)
+    }
+}
+
+/// The counts in the TZif header.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+fn header(r: &mut Reader) -> Option<Header> {
+    if try_opt!(r.take(4)) != b"TZif" {
+        return None;
+    }
+    let version = try_opt!(r.u8());
+    try_opt!(r.take(15));
+    let isutcnt = try_opt!(r.u32()) as usize;
+    let isstdcnt = try_opt!(r.u32()) as usize;
+    let leapcnt = try_opt!(r.u32()) as usize;
+    let timecnt = try_opt!(r.u32()) as usize;
+    let typecnt = try_opt!(r.u32()) as usize;
+    let charcnt = try_opt!(r.u32()) as usize;
+    if typecnt == 0 || charcnt == 0 ||
+       (isutcnt != 0 && isutcnt != typecnt) || (isstdcnt != 0 && isstdcnt != typecnt) {
+        return None;
+    }
+    Some(Header { version: version, isutcnt: isutcnt, isstdcnt: isstdcnt, leapcnt: leapcnt,
+                  timecnt: timecnt, typecnt: typecnt, charcnt: charcnt })
+}
+
+/// Returns the size of the data block following given header in bytes,
+/// or `None` if it overflows.
+fn data_block_size(h: &Header, wide: bool) -> Option<usize> {
+    let (time_size, leap_size) = if wide { (8, 12) } else { (4, 8) };
+    let size = try_opt!(h.timecnt.checked_mul(time_size + 1));
+    let size = try_opt!(size.checked_add(try_opt!(h.typecnt.checked_mul(6))));
+    let size = try_opt!(size.checked_add(h.charcnt));
+    let size = try_opt!(size.checked_add(try_opt!(h.leapcnt.checked_mul(leap_size))));
+    let size = try_opt!(size.checked_add(h.isstdcnt));
+    size.checked_add(h.isutcnt)
+}
+
+/// Parses the data block following given header.
+/// `wide` selects the 64-bit format of the version 2 and later.
+fn data_block(r: &mut Reader, h: &Header, wide: bool) -> Option<TzifData> {
+    // the counts are untrusted, so check them against the input before allocating
+    let size = try_opt!(data_block_size(h, wide));
+    if size > r.bytes.len() - r.pos {
+        return None;
+    }
+
+    let mut times = Vec::with_capacity(h.timecnt);
+    for _ in 0..h.timecnt {
+        times.push(if wide { try_opt!(r.i64()) } else { try_opt!(r.i32()) as i64 });
+    }
+    let mut transitions = Vec::with_capacity(h.timecnt);
+    for &t in &times {
+        let idx = try_opt!(r.u8()) as usize;
+        if idx >= h.typecnt {
+            return None;
+        }
+        transitions.push((t, idx));
+    }
+    if transitions.windows(2).any(|w| w[0].0 >= w[1].0) {
+        return None;
+    }
+
+    let mut raw_types = Vec::with_capacity(h.typecnt);
+    for _ in 0..h.typecnt {
+        let utoff = try_opt!(r.i32());
+        let is_dst = try_opt!(r.u8());
+        let abbr_idx = try_opt!(r.u8()) as usize;
+        if utoff <= -86_400 || utoff >= 86_400 || is_dst > 1 || abbr_idx >= h.charcnt {
+            return None;
+        }
+        raw_types.push((utoff, is_dst == 1, abbr_idx));
+    }
+    let chars = try_opt!(r.take(h.charcnt));
+    let mut types = Vec::with_capacity(h.typecnt);
+    for (utoff, is_dst, abbr_idx) in raw_types {
+        let len = try_opt!(chars[abbr_idx..].iter().position(|&c| c == 0));
+        let abbr = try_opt!(String::from_utf8(chars[abbr_idx..abbr_idx + len].to_vec()).ok());
+        types.push(LocalTimeType { utoff: utoff, is_dst: is_dst, abbr: abbr });
+    }
+
+    // leap second records and standard/wall and UT/local indicators are not used
+    let leap_size = if wide { 12 } else { 8 };
+    let skipped = try_opt!(h.leapcnt.checked_mul(leap_size));
+    let skipped = try_opt!(skipped.checked_add(h.isstdcnt));
+    try_opt!(r.take(try_opt!(skipped.checked_add(h.isutcnt))));
+    Some(TzifData { transitions: transitions, types: types, footer: None })
+}
+
+/// Parses the TZif file of any version.
+pub fn parse(bytes: &[u8]) -> Option<TzifData> {
+    let mut r = Reader { bytes: bytes, pos: 0 };
+    let h = try_opt!(header(&mut r));
+    if h.version == 0 {
+        return data_block(&mut r, &h, false);
+    }
+
+    // skip the version 1 data block in favor of the 64-bit one
+    try_opt!(r.take(try_opt!(data_block_size(&h, false))));
+    let h = try_opt!(header(&mut r));
+    let mut data = try_opt!(data_block(&mut r, &h, true));
+
+    if try_opt!(r.u8()) != b'\n' {
+        return None;
+    }
+    let rest = &r.bytes[r.pos..];
+    let len = try_opt!(rest.iter().position(|&c| c == b'\n'));
+    let footer = try_opt!(String::from_utf8(rest[..len].to_vec()).ok());
+    if !footer.is_empty() {
+        data.footer = Some(footer);
+    }
+    Some(data)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{parse, LocalTimeType};
+
+    /// Builds a TZif file of version 2 from given transitions, types and footer.
+    pub fn build(transitions: &[(i64, u8)], types: &[(i32, bool, &str)], footer: &str) -> Vec<u8> {
+        fn header(out: &mut Vec<u8>, timecnt: usize, typecnt: usize, charcnt: usize) {
+            out.extend(b"TZif2");
+            out.extend(&[0; 15]);
+            for &n in &[0, 0, 0, timecnt, typecnt, charcnt] {
+                out.extend(&[(n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8]);
+            }
+        }
+        fn be(out: &mut Vec<u8>, v: i64, size: usize) {
+            for i in (0..size).rev() {
+                out.push((v >> (i * 8)) as u8);
+            }
+        }
+
+        let mut chars = Vec::new();
+        let mut abbr_idx = Vec::new();
+        for &(_, _, abbr) in types {
+            abbr_idx.push(chars.len());
+            chars.extend(abbr.as_bytes());
+            chars.push(0);
+        }
+
+        let mut out = Vec::new();
+        for &wide in &[false, true] {
+            let size = if wide { 8 } else { 4 };
+            header(&mut out, transitions.len(), types.len(), chars.len());
+            for &(t, _) in transitions {
+                be(&mut out, t, size);
+            }
+            for &(_, idx) in transitions {
+                out.push(idx);
+            }
+            for (&(utoff, is_dst, _), &idx) in types.iter().zip(&abbr_idx) {
+                be(&mut out, utoff as i64, 4);
+                out.push(is_dst as u8);
+                out.push(idx as u8);
+            }
+            out.extend(&chars);
+        }
+        out.push(b'\n');
+        out.extend(footer.as_bytes());
+        out.push(b'\n');
+        out
+    }
+
+    #[test]
+    fn test_tzif_parse() {
+        let bytes = build(&[(-100, 1), (100, 0)], &[(3600, false, "ABC"), (7200, true, "ABDT")],
+                          "ABC-1");
+        let data = parse(&bytes).unwrap();
+        assert_eq!(data.transitions, vec![(-100, 1), (100, 0)]);
+        assert_eq!(data.types, vec![
+            LocalTimeType { utoff: 3600, is_dst: false, abbr: "ABC".to_owned() },
+            LocalTimeType { utoff: 7200, is_dst: true, abbr: "ABDT".to_owned() },
+        ]);
+        assert_eq!(data.footer, Some("ABC-1".to_owned()));
+
+        let data = parse(&build(&[], &[(0, false, "UTC")], "")).unwrap();
+        assert_eq!(data.footer, None);
+
+        // truncated, out-of-order or otherwise broken files
+        assert_eq!(parse(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(parse(&bytes[..40]), None);
+        assert_eq!(parse(b"TZjf"), None);
+        assert_eq!(parse(&build(&[(100, 0), (-100, 0)], &[(0, false, "UTC")], "")), None);
+        assert_eq!(parse(&build(&[(100, 1)], &[(0, false, "UTC")], "")), None);
+        assert_eq!(parse(&build(&[], &[(86_400, false, "UTC")], "")), None);
+
+        // huge counts in the header alone should not be trusted
+        for &version in &[0, b'2'] {
+            let mut bytes = b"TZif".to_vec();
+            bytes.push(version);
+            bytes.extend(&[0; 15]);
+            for &n in &[0u32, 0, 0, 0xffff_ffff, 1, 1] {
+                bytes.extend(&[(n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8]);
+            }
+            assert_eq!(bytes.len(), 44);
+            assert_eq!(parse(&bytes), None);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A set of the days of week.
+
+use std::{str, fmt};
+use std::iter::FromIterator;
+use num::traits::FromPrimitive;
+
+use {Weekday, ParseWeekdayError};
+
+/// A set of the days of week, stored compactly as bit flags.
+///
+/// The iteration order is always from Monday to Sunday, regardless of the insertion order.
+/// Its `Display` and `FromStr` implementations use the comma-separated two-letter codes
+/// of iCalendar (RFC 5545), like `MO,WE,FR`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Weekday, WeekdaySet};
+///
+/// let set: WeekdaySet = "MO,WE,FR".parse().unwrap();
+/// assert!(set.contains(Weekday::Wed));
+/// assert!(!set.contains(Weekday::Thu));
+/// assert_eq!(set.len(), 3);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![Weekday::Mon, Weekday::Wed, Weekday::Fri]);
+/// assert_eq!(set.to_string(), "MO,WE,FR");
+/// assert_eq!(set.union(WeekdaySet::weekend()).to_string(), "MO,WE,FR,SA,SU");
+/// ~~~~
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Default)]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    /// Makes a new empty set.
+    #[inline]
+    pub fn empty() -> WeekdaySet {
+        WeekdaySet(0)
+    }
+
+    /// Makes a new set containing all days of week.
+    #[inline]
+    pub fn all() -> WeekdaySet {
+        WeekdaySet(0b111_1111)
+    }
+
+    /// Makes a new set containing the days from Monday to Friday.
+    #[inline]
+    pub fn workdays() -> WeekdaySet {
+        WeekdaySet(0b001_1111)
+    }
+
+    /// Makes a new set containing Saturday and Sunday.
+    #[inline]
+    pub fn weekend() -> WeekdaySet {
+        WeekdaySet(0b110_0000)
+    }
+
+    /// Makes a new set containing given day of week only.
+    #[inline]
+    pub fn single(weekday: Weekday) -> WeekdaySet {
+        WeekdaySet(bit(weekday))
+    }
+
+    /// Returns `true` if the set contains given day of week.
+    #[inline]
+    pub fn contains(&self, weekday: Weekday) -> bool {
+        self.0 & bit(weekday) != 0
+    }
+
+    /// Adds given day of week to the set.
+    /// Returns `true` if the set did not contain it.
+    #[inline]
+    pub fn insert(&mut self, weekday: Weekday) -> bool {
+        let absent = !self.contains(weekday);
+        self.0 |= bit(weekday);
+        absent
+    }
+
+    /// Removes given day of week from the set.
+    /// Returns `true` if the set did contain it.
+    #[inline]
+    pub fn remove(&mut self, weekday: Weekday) -> bool {
+        let present = self.contains(weekday);
+        self.0 &= !bit(weekday);
+        present
+    }
+
+    /// Returns the number of days of week in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns `true` if the set contains no day of week.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns a set containing the days of week in either set.
+    #[inline]
+    pub fn union(&self, other: WeekdaySet) -> WeekdaySet {
+        WeekdaySet(self.0 | other.0)
+    }
+
+    /// Returns a set containing the days of week in both sets.
+    #[inline]
+    pub fn intersection(&self, other: WeekdaySet) -> WeekdaySet {
+        WeekdaySet(self.0 & other.0)
+    }
+
+    /// Returns a set containing the days of week in this set but not in the other set.
+    #[inline]
+    pub fn difference(&self, other: WeekdaySet) -> WeekdaySet {
+        WeekdaySet(self.0 & !other.0)
+    }
+
+    /// Returns a set containing the days of week not in this set.
+    #[inline]
+    pub fn complement(&self) -> WeekdaySet {
+        WeekdaySet(!self.0 & WeekdaySet::all().0)
+    }
+
+    /// Returns an iterator over the days of week in the set, from Monday to Sunday.
+    #[inline]
+    pub fn iter(&self) -> WeekdaySetIter {
+        WeekdaySetIter { bits: self.0 }
+    }
+}
+
+#[inline]
+fn bit(weekday: Weekday) -> u8 {
+    1 << weekday.num_days_from_monday()
+}
+
+/// The two-letter code of iCalendar (RFC 5545) for given day of week.
+fn code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Parses the two-letter code of iCalendar (RFC 5545) case-insensitively.
+fn two_letter_code(s: &str) -> Option<Weekday> {
+    let b = s.as_bytes();
+    if b.len() != 2 {
+        return None;
+    }
+    match (b[0] | 32, b[1] | 32) {
+        (b'm', b'o') => Some(Weekday::Mon),
+        (b't', b'u') => Some(Weekday::Tue),
+        (b'w', b'e') => Some(Weekday::Wed),
+        (b't', b'h') => Some(Weekday::Thu),
+        (b'f', b'r') => Some(Weekday::Fri),
+        (b's', b'a') => Some(Weekday::Sat),
+        (b's', b'u') => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+impl From<Weekday> for WeekdaySet {
+    fn from(weekday: Weekday) -> WeekdaySet {
+        WeekdaySet::single(weekday)
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<I: IntoIterator<Item=Weekday>>(iter: I) -> WeekdaySet {
+        let mut set = WeekdaySet::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Weekday> for WeekdaySet {
+    fn extend<I: IntoIterator<Item=Weekday>>(&mut self, iter: I) {
+        for weekday in iter {
+            self.insert(weekday);
+        }
+    }
+}
+
+impl IntoIterator for WeekdaySet {
+    type Item = Weekday;
+    type IntoIter = WeekdaySetIter;
+
+    fn into_iter(self) -> WeekdaySetIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the days of week in [`WeekdaySet`](./struct.WeekdaySet.html),
+/// from Monday to Sunday.
+#[derive(Clone, Debug)]
+pub struct WeekdaySetIter {
+    bits: u8,
+}
+
+impl Iterator for WeekdaySetIter {
+    type Item = Weekday;
+
+    fn next(&mut self) -> Option<Weekday> {
+        if self.bits == 0 {
+            return None;
+        }
+        let n = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1; // clears the lowest set bit
+        Weekday::from_u32(n)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for WeekdaySetIter {}
+
+impl fmt::Debug for WeekdaySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The `Display` output uses the comma-separated two-letter codes like `MO,WE,FR`.
+/// An empty set is displayed as an empty string.
+impl fmt::Display for WeekdaySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, weekday) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", code(weekday))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsing a `str` into a `WeekdaySet` accepts the comma-separated list of the days of week,
+/// each being either a two-letter code (like `MO`) or any form accepted by `Weekday`.
+/// The letter case and the whitespace around each day are ignored.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Weekday, WeekdaySet};
+///
+/// let set = WeekdaySet::single(Weekday::Sat).union(WeekdaySet::single(Weekday::Sun));
+/// assert_eq!("SA,SU".parse::<WeekdaySet>(), Ok(set));
+/// assert_eq!("saturday, Sun".parse::<WeekdaySet>(), Ok(set));
+/// assert_eq!("".parse::<WeekdaySet>(), Ok(WeekdaySet::empty()));
+/// assert!("SA,,SU".parse::<WeekdaySet>().is_err());
+/// assert!("SA;SU".parse::<WeekdaySet>().is_err());
+/// ~~~~
+impl str::FromStr for WeekdaySet {
+    type Err = ParseWeekdayError;
+
+    fn from_str(s: &str) -> Result<WeekdaySet, ParseWeekdayError> {
+        let mut set = WeekdaySet::empty();
+        if s.trim().is_empty() {
+            return Ok(set);
+        }
+        for part in s.split(',') {
+            let part = part.trim();
+            let weekday = match two_letter_code(part) {
+                Some(weekday) => weekday,
+                None => part.parse()?,
+            };
+            set.insert(weekday);
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Weekday;
+    use super::WeekdaySet;
+
+    #[test]
+    fn test_weekday_set() {
+        let mut set = WeekdaySet::empty();
+        assert!(set.is_empty());
+        assert!(set.insert(Weekday::Sun));
+        assert!(set.insert(Weekday::Mon));
+        assert!(!set.insert(Weekday::Sun));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![Weekday::Mon, Weekday::Sun]);
+        assert_eq!(set.iter().len(), 2);
+        assert!(set.remove(Weekday::Mon));
+        assert!(!set.remove(Weekday::Mon));
+        assert_eq!(set, WeekdaySet::single(Weekday::Sun));
+
+        assert_eq!(WeekdaySet::all().len(), 7);
+        assert_eq!(WeekdaySet::workdays().union(WeekdaySet::weekend()), WeekdaySet::all());
+        assert_eq!(WeekdaySet::workdays().complement(), WeekdaySet::weekend());
+        assert_eq!(WeekdaySet::all().difference(WeekdaySet::weekend()), WeekdaySet::workdays());
+        assert!(WeekdaySet::workdays().intersection(WeekdaySet::weekend()).is_empty());
+        assert_eq!(WeekdaySet::all().into_iter().collect::<WeekdaySet>(), WeekdaySet::all());
+    }
+
+    #[test]
+    fn test_weekday_set_fmt() {
+        assert_eq!(WeekdaySet::empty().to_string(), "");
+        assert_eq!(WeekdaySet::all().to_string(), "MO,TU,WE,TH,FR,SA,SU");
+        assert_eq!(format!("{:?}", WeekdaySet::weekend()), "{Sat, Sun}");
+
+        for &s in &["MO,TU,WE,TH,FR,SA,SU", "mo, tu ,We,thu,friday,SAT,Sunday",
+                    "su,sa,fr,th,we,tu,mo,mo"] {
+            assert_eq!(s.parse::<WeekdaySet>(), Ok(WeekdaySet::all()));
+        }
+        for &s in &["MO,", ",MO", "MO TU", "M", "MON-FRI"] {
+            assert!(s.parse::<WeekdaySet>().is_err(), "{:?} should be an error", s);
+        }
+    }
+}